@@ -113,6 +113,13 @@
 //! --verbose
 //!```
 //!
+//! Flags with single character aliases can also be combined, unless
+//! the combination is itself an alias e.g:
+//!
+//!```ignore
+//! -hv
+//!```
+//!
 //! *Note:* flag arguments do not take values
 //!
 //! ## 2. Single Value Arguments
@@ -128,6 +135,24 @@
 //! --username Zelensky
 //!```
 //!
+//! Single value arguments can also take their value after an equals
+//! sign e.g:
+//!
+//!```ignore
+//! -o=filename.txt
+//! --output=filename.txt
+//! --username=Zelensky
+//!```
+//!
+//! Single value arguments with single character aliases can also end
+//! a combination of flags, taking the rest of the combination as their
+//! value, or the next value if there's nothing left e.g:
+//!
+//!```ignore
+//! -vofilename.txt
+//! -vo filename.txt
+//!```
+//!
 //! ## 3. Multiple Value Arguments
 //!
 //! Multiple value arguments contain at least a single `String` value
@@ -157,6 +182,12 @@
 //! --host host1 host2 hostN
 //!```
 //!
+//! The first value can also be supplied after an equals sign e.g:
+//!
+//!```ignore
+//! --input=file1.txt file2.txt ... fileN.txt
+//!```
+//!
 //! ## 4. Leftover Arguments
 //!
 //! Leftover argument values are values supplied on the command line
@@ -168,45 +199,42 @@
 //! ls file1 file2... fileN
 //!```
 //!
-//! - any values after the double-dash argument e.g:
+//! - any values after the double-dash argument, even values that
+//!   look like arguments e.g:
 //!
-//!```ignore
-//! ls -l -R  -- file1 file2... fileN`
+//!```text
+//! ls -l -R  -- file1 file2... fileN
 //!```
 //!
-//! - any value supplied to flags, because flags do not accept values
-//!
-//! - any remaining values supplied to singles value arguments,
-//! because these only take a one value
-//!
-//! # Caveats
-//!
-//! - Combining flags is currently unsupported i.e the following does
-//! not work:
+//! - a lone dash, which conventionally means stdin e.g:
 //!
-//!```ignore
-//! tar -zcf filename.tar.gz *
+//!```text
+//! cat file1 - file2
 //!```
 //!
-//! - Equals-Value is currently unsupported i.e the following does not
-//! work:
+//! - any value supplied to flags, because flags do not accept values
 //!
-//!```ignore
-//! tar -zc --file=filename.tar.gz
-//!```
+//! - any remaining values supplied to singles value arguments,
+//!   because these only take a one value
 //!
-//! - Commands with their own separate `Clappers` parser is currently
-//! unsupported i.e the following does not work:
+//! # Subcommands
+//!
+//! Commands with their own separate `Clappers` parser are configured
+//! with `set_subcommand()`. The first value on the command line that
+//! matches a configured subcommand hands all remaining command line
+//! arguments over to that subcommand's parser e.g:
 //!
 //!```ignore
 //! apt-get -y install -f cargo
 //! apt-get update -f
 //!```
 //!
+//! # Caveats
+//!
 //! - Command line argument values are always `String` types. This was
-//! by design, and no convenience functions are planned. To convert a
-//! `String` to something else, use `String`'s built-in `parse()`
-//! function instead:
+//!   by design, and no convenience functions are planned. To convert a
+//!   `String` to something else, use `String`'s built-in `parse()`
+//!   function instead:
 //!
 //!```
 //! use clappers::Clappers;
@@ -223,15 +251,27 @@
 //!```
 //!
 
+#![allow(clippy::needless_doctest_main)]
+
 use std::{
+    cell::Cell,
     collections::{HashMap, HashSet},
-    env,
+    env, error,
+    ffi::{OsStr, OsString},
+    fmt,
+    io::{self, BufRead, BufReader, Read, Write},
+    iter::Peekable,
+    path::Path,
+    sync::{Mutex, MutexGuard},
 };
 
 #[derive(Clone, Debug)]
 struct ConfigType {
     name: HashSet<String>,
     aliases: HashMap<String, String>,
+    specs: Vec<Vec<String>>,
+    defaults: HashMap<String, String>,
+    misplaced_defaults: Vec<String>,
 }
 
 impl ConfigType {
@@ -239,12 +279,22 @@ impl ConfigType {
         Self {
             name: HashSet::new(),
             aliases: HashMap::new(),
+            specs: Vec::new(),
+            defaults: HashMap::new(),
+            misplaced_defaults: Vec::new(),
         }
     }
 
-    fn add_to_config(&mut self, arg_specs: Vec<&str>) {
+    fn add_to_config(&mut self, arg_specs: Vec<&str>, takes_values: bool) {
         for arg_spec in arg_specs {
-            let arguments: Vec<&str> = arg_spec.split('|').collect();
+            // o|output=a.out
+            let (arg_spec, default) = match arg_spec.split_once('=') {
+                Some((arg_spec, default)) => (arg_spec, Some(default)),
+                None => (arg_spec, None),
+            };
+
+            // an empty alias can never be supplied on the command line
+            let arguments: Vec<&str> = arg_spec.split('|').filter(|a| !a.is_empty()).collect();
 
             if arguments.is_empty() {
                 continue;
@@ -256,6 +306,19 @@ impl ConfigType {
                 self.aliases
                     .insert(argument.to_string(), arguments[0].to_string());
             }
+
+            self.specs
+                .push(arguments.iter().map(|a| a.to_string()).collect());
+
+            // flags don't take values, so a default is a mistake for validate_config() to report
+            if let Some(default) = default {
+                if takes_values {
+                    self.defaults
+                        .insert(arguments[0].to_string(), default.to_string());
+                } else {
+                    self.misplaced_defaults.push(arguments[0].to_string());
+                }
+            }
         }
     }
 }
@@ -265,6 +328,59 @@ struct Config {
     flags: ConfigType,
     singles: ConfigType,
     multiples: ConfigType,
+    subcommands: HashMap<String, Clappers>,
+    min_lengths: Vec<(String, usize)>,
+    max_lengths: Vec<(String, usize)>,
+    exact_values: Vec<(String, usize)>,
+    reject_empty: Vec<String>,
+    multiples_merge: Vec<(String, MergePolicy)>,
+    from_stdin: HashSet<String>,
+    required: Vec<String>,
+    possible_values: Vec<(String, Vec<String>)>,
+    flag_envs: HashMap<String, String>,
+    value_envs: Vec<(String, String)>,
+    requires_all: Vec<(String, Vec<String>)>,
+    requires_any: Vec<(String, Vec<String>)>,
+    together: Vec<Vec<String>>,
+    terminator_flags: HashSet<String>,
+    terminators: Vec<String>,
+    short_circuits: HashSet<String>,
+    bracketed: HashMap<String, (String, String)>,
+    negatable: HashSet<String>,
+    usage_order: Vec<ArgKind>,
+    skip_leading: Option<String>,
+    consumes_unknown_dash: HashSet<String>,
+    consumes_terminator: HashSet<String>,
+    descriptions: HashMap<String, String>,
+    usage_width: usize,
+    leftovers_metavar: Option<String>,
+    leftovers_description: Option<String>,
+    about: Option<String>,
+    capacity: (usize, usize, usize),
+    no_leftovers: bool,
+    strict_unknown: bool,
+    strict_missing_value: bool,
+    numbers_as_leftovers: bool,
+}
+
+// the arguments read through the getters, behind a Mutex so a built Clappers can be shared
+// between threads
+#[derive(Debug, Default)]
+struct ReadSet(Mutex<HashSet<String>>);
+
+impl ReadSet {
+    // a panic while holding the lock can't leave the set half updated
+    fn lock(&self) -> MutexGuard<'_, HashSet<String>> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Clone for ReadSet {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.lock().clone()))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -272,6 +388,427 @@ struct Values {
     flags: HashSet<String>,
     singles: HashMap<String, String>,
     multiples: HashMap<String, Vec<String>>,
+    subcommand: Option<(String, Box<Clappers>)>,
+    program: String,
+    trailing: Option<usize>,
+    env_flags: HashSet<String>,
+    env_values: HashSet<String>,
+    negated: HashSet<String>,
+    unknown: Vec<String>,
+    unknown_values: HashMap<String, Vec<String>>,
+    dangling: HashSet<String>,
+    leftovers: Vec<String>,
+    short_circuited: Option<String>,
+    unbalanced: Vec<String>,
+    invalid: Vec<String>,
+    read: ReadSet,
+    lossy: Vec<usize>,
+    occurrences: HashMap<String, usize>,
+    leftover_positions: Vec<usize>,
+    first_positions: HashMap<String, usize>,
+    #[cfg_attr(not(unix), allow(dead_code))]
+    raw_args: Vec<OsString>,
+}
+
+impl Values {
+    fn new() -> Self {
+        Self {
+            flags: HashSet::new(),
+            singles: HashMap::new(),
+            multiples: HashMap::new(),
+            subcommand: None,
+            program: String::new(),
+            trailing: None,
+            env_flags: HashSet::new(),
+            env_values: HashSet::new(),
+            negated: HashSet::new(),
+            unknown: Vec::new(),
+            unknown_values: HashMap::new(),
+            dangling: HashSet::new(),
+            leftovers: Vec::new(),
+            short_circuited: None,
+            unbalanced: Vec::new(),
+            invalid: Vec::new(),
+            read: ReadSet::default(),
+            lossy: Vec::new(),
+            occurrences: HashMap::new(),
+            leftover_positions: Vec::new(),
+            first_positions: HashMap::new(),
+            raw_args: Vec::new(),
+        }
+    }
+}
+
+/// Errors returned by `Clappers::try_build()` when the command line breaks the `Clappers` config
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A value supplied for `argument` failed validation
+    InvalidValue { argument: String, value: String },
+    /// `argument` was supplied without all of the arguments it requires
+    RequiresAll {
+        argument: String,
+        missing: Vec<String>,
+    },
+    /// `argument` was supplied without any of the arguments it requires
+    RequiresAny {
+        argument: String,
+        needs: Vec<String>,
+    },
+    /// Only some of a `group` of arguments that go together were supplied
+    UnmetRequirement {
+        group: Vec<String>,
+        missing: Vec<String>,
+    },
+    /// `argument` was supplied with the wrong number of values
+    WrongValueCount {
+        argument: String,
+        expected: usize,
+        found: usize,
+    },
+    /// The required `argument` was not supplied
+    MissingRequired { argument: String },
+    /// The values of a bracketed `argument` were opened but never closed
+    UnbalancedBracket { argument: String },
+    /// The `token` looks like an argument, but is malformed e.g "--=value"
+    InvalidToken { token: String },
+    /// An empty value was supplied for `argument`
+    EmptyValue { argument: String },
+    /// The leftover `value` was supplied, but leftovers aren't allowed
+    UnexpectedPositional { value: String },
+    /// The `argument` was supplied, but isn't in the `Clappers` config,
+    /// along with the closest configured alias, if there is one
+    UnknownArgument {
+        argument: String,
+        suggestion: Option<String>,
+    },
+    /// The single value `argument` was supplied without a value
+    MissingValue { argument: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidValue { argument, value } => {
+                write!(f, "invalid value `{value}` for argument `{argument}`")
+            }
+            ParseError::RequiresAll { argument, missing } => {
+                write!(
+                    f,
+                    "argument `{argument}` also requires `{}`",
+                    missing.join("`, `")
+                )
+            }
+            ParseError::RequiresAny { argument, needs } => {
+                write!(
+                    f,
+                    "argument `{argument}` requires one of `{}`",
+                    needs.join("`, `")
+                )
+            }
+            ParseError::UnmetRequirement { group, missing } => {
+                write!(
+                    f,
+                    "arguments `{}` go together, but `{}` missing",
+                    group.join("`, `"),
+                    missing.join("`, `")
+                )
+            }
+            ParseError::WrongValueCount {
+                argument,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "argument `{argument}` takes {expected} values, but {found} were supplied"
+                )
+            }
+            ParseError::MissingRequired { argument } => {
+                write!(f, "argument `{argument}` is required")
+            }
+            ParseError::UnbalancedBracket { argument } => {
+                write!(f, "argument `{argument}` has an unclosed bracket")
+            }
+            ParseError::InvalidToken { token } => {
+                write!(f, "`{token}` is not a valid argument")
+            }
+            ParseError::EmptyValue { argument } => {
+                write!(f, "argument `{argument}` cannot be empty")
+            }
+            ParseError::UnexpectedPositional { value } => {
+                write!(f, "unexpected value `{value}`")
+            }
+            ParseError::UnknownArgument {
+                argument,
+                suggestion,
+            } => {
+                write!(f, "unknown argument `{argument}`")?;
+
+                match suggestion {
+                    Some(suggestion) => write!(f, "; did you mean `{suggestion}`?"),
+                    None => Ok(()),
+                }
+            }
+            ParseError::MissingValue { argument } => {
+                write!(f, "argument `{argument}` is missing its value")
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Errors returned by `Clappers::validate_config()` when the `Clappers` config is inconsistent
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// Arguments of different types share the canonical `name`
+    DuplicateCanonical { name: String },
+    /// The flag argument with the canonical `name` was configured with
+    /// a default value, but flags don't take values
+    FlagDefault { name: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::DuplicateCanonical { name } => {
+                write!(f, "argument `{name}` is configured as more than one type")
+            }
+            ConfigError::FlagDefault { name } => {
+                write!(f, "flag `{name}` can't have a default value")
+            }
+        }
+    }
+}
+
+impl error::Error for ConfigError {}
+
+/// The type of an argument
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArgKind {
+    /// Flag arguments
+    Flag,
+    /// Single value arguments
+    Single,
+    /// Multiple value arguments
+    Multiple,
+}
+
+/// How multiple values are combined when the same argument has values from more than one source
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// Keep the existing values, followed by the new values
+    Append,
+    /// Replace the existing values with the new values
+    Replace,
+    /// Keep the existing values, followed by the new values not already kept
+    Unique,
+}
+
+/// Where the value of an argument came from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueSource {
+    /// Supplied on the command line
+    CommandLine,
+    /// Read from an environment variable
+    Environment,
+    /// Reserved for values loaded from a configuration file, once
+    /// layered config is supported. Never returned yet
+    ConfigFile,
+    /// Turned off on the command line by its negation e.g `--no-color`,
+    /// so the flag has no value
+    Negated,
+    /// The default value configured in the `arg_spec`
+    Default,
+    /// No value at all
+    Unset,
+}
+
+// the layout of help_json(), which tools rely on
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+struct JsonHelp<'a> {
+    program: &'a str,
+    arguments: Vec<JsonArgument<'a>>,
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+struct JsonArgument<'a> {
+    name: &'a str,
+    aliases: &'a [String],
+    #[serde(rename = "type")]
+    kind: &'static str,
+    description: String,
+    default: Option<&'a str>,
+    required: bool,
+    possible_values: Vec<String>,
+}
+
+/// A snapshot of the values parsed from the command line, returned by `Clappers::get_parsed_values()`
+///
+/// Every argument is keyed by its canonical name, and only values
+/// supplied on the command line or by the environment are included.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParsedValues {
+    /// The flag arguments that are set
+    pub flags: HashSet<String>,
+    /// The single value arguments, along with their value
+    pub singles: HashMap<String, String>,
+    /// The multiple value arguments, along with their values
+    pub multiples: HashMap<String, Vec<String>>,
+    /// The leftover values
+    pub leftovers: Vec<String>,
+}
+
+/// A subcommand handler called by `Clappers::dispatch()`
+pub type Handler<T> = Box<dyn Fn(&Clappers) -> T>;
+
+/// A single argument with all of its attributes, added to the `Clappers` config by `Clappers::arg()`
+#[derive(Clone, Debug)]
+pub struct ArgBuilder {
+    spec: String,
+    kind: ArgKind,
+    description: Option<String>,
+    default: Option<String>,
+    required: bool,
+    possible_values: Option<Vec<String>>,
+    env: Option<String>,
+}
+
+impl ArgBuilder {
+    /// Start building an argument
+    ///
+    /// The argument is a flag unless its kind is changed with `kind()`.
+    ///
+    /// # Parameters
+    ///
+    /// `spec` contains "|" separated alias names e.g "o|output"
+    ///
+    /// # Return value
+    ///
+    /// The `ArgBuilder` so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{ArgBuilder, ArgKind, Clappers};
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .arg(ArgBuilder::new("v|verbose"))
+    ///         .arg(ArgBuilder::new("o|output").kind(ArgKind::Single))
+    ///         .build();
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    pub fn new(spec: &str) -> Self {
+        Self {
+            spec: spec.to_string(),
+            kind: ArgKind::Flag,
+            description: None,
+            default: None,
+            required: false,
+            possible_values: None,
+            env: None,
+        }
+    }
+
+    /// Set the type of the argument
+    ///
+    /// # Parameters
+    ///
+    /// `kind` is the type of the argument
+    ///
+    /// # Return value
+    ///
+    /// The `ArgBuilder` so that it can be chained
+    ///
+    pub fn kind(mut self, kind: ArgKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Describe the argument, just like `Clappers::describe()`
+    ///
+    /// # Parameters
+    ///
+    /// `description` is a short description of what the argument does
+    ///
+    /// # Return value
+    ///
+    /// The `ArgBuilder` so that it can be chained
+    ///
+    pub fn describe(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Set the default value of a single or multiple value argument
+    ///
+    /// Flags can't have a default value, so `Clappers::validate_config()`
+    /// reports one as `ConfigError::FlagDefault`.
+    ///
+    /// # Parameters
+    ///
+    /// `value` is used when the argument is not supplied on the
+    /// command line
+    ///
+    /// # Return value
+    ///
+    /// The `ArgBuilder` so that it can be chained
+    ///
+    pub fn default(mut self, value: &str) -> Self {
+        self.default = Some(value.to_string());
+        self
+    }
+
+    /// Require the argument, just like `Clappers::required()`
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The `ArgBuilder` so that it can be chained
+    ///
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Restrict the values of the argument, just like `Clappers::possible_values()`
+    ///
+    /// # Parameters
+    ///
+    /// `values` is the list of allowed values
+    ///
+    /// # Return value
+    ///
+    /// The `ArgBuilder` so that it can be chained
+    ///
+    pub fn possible_values(mut self, values: Vec<&str>) -> Self {
+        self.possible_values = Some(values.iter().map(|value| value.to_string()).collect());
+        self
+    }
+
+    /// Fall back to an environment variable, just like `Clappers::flag_env()` for flags and `Clappers::value_env()` otherwise
+    ///
+    /// # Parameters
+    ///
+    /// `var` is the name of the environment variable
+    ///
+    /// # Return value
+    ///
+    /// The `ArgBuilder` so that it can be chained
+    ///
+    pub fn env(mut self, var: &str) -> Self {
+        self.env = Some(var.to_string());
+        self
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -321,13 +858,98 @@ impl Clappers {
                 flags: ConfigType::new(),
                 singles: ConfigType::new(),
                 multiples: ConfigType::new(),
+                subcommands: HashMap::new(),
+                min_lengths: Vec::new(),
+                max_lengths: Vec::new(),
+                exact_values: Vec::new(),
+                reject_empty: Vec::new(),
+                multiples_merge: Vec::new(),
+                from_stdin: HashSet::new(),
+                required: Vec::new(),
+                possible_values: Vec::new(),
+                flag_envs: HashMap::new(),
+                value_envs: Vec::new(),
+                requires_all: Vec::new(),
+                requires_any: Vec::new(),
+                together: Vec::new(),
+                terminator_flags: HashSet::new(),
+                terminators: vec!["--".to_string()],
+                short_circuits: HashSet::new(),
+                bracketed: HashMap::new(),
+                negatable: HashSet::new(),
+                usage_order: vec![ArgKind::Flag, ArgKind::Single, ArgKind::Multiple],
+                skip_leading: None,
+                consumes_unknown_dash: HashSet::new(),
+                consumes_terminator: HashSet::new(),
+                descriptions: HashMap::new(),
+                leftovers_metavar: None,
+                leftovers_description: None,
+                about: None,
+                capacity: (0, 0, 0),
+                no_leftovers: false,
+                strict_unknown: false,
+                strict_missing_value: false,
+                numbers_as_leftovers: false,
+                usage_width: 80,
             },
-            values: Values {
-                flags: HashSet::new(),
-                singles: HashMap::new(),
-                multiples: HashMap::new(),
-            },
+            values: Values::new(),
+        }
+    }
+
+    /// Creates a `Clappers` parser configured from a getopt(3) style option string
+    ///
+    /// Each character is a single character argument, which is a
+    /// single value argument when followed by ":", and a flag
+    /// otherwise e.g "hvo:" configures the flags `-h` and `-v`, and
+    /// the single value argument `-o`
+    ///
+    /// # Parameters
+    ///
+    /// `optstring` is the getopt(3) style option string.
+    ///
+    /// # Return value
+    ///
+    /// A configured `Clappers` parser, which can be chained further
+    /// before `build()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::from_getopt_string("hvo:i:")
+    ///         .build_from(vec!["prog", "-v", "-o", "a.out", "-i", "file1", "file2"]);
+    ///
+    ///     assert_eq!(clappers.count_configured(), (2, 2, 0));
+    ///     assert!(!clappers.get_flag("h"));
+    ///     assert!(clappers.get_flag("v"));
+    ///     assert_eq!(clappers.get_single("o"), "a.out");
+    ///     assert_eq!(clappers.get_single("i"), "file1");
+    ///     assert_eq!(clappers.get_leftovers(), vec!["file2"]);
+    /// }
+    /// ```
+    ///
+    pub fn from_getopt_string(optstring: &str) -> Self {
+        let mut flags: Vec<String> = Vec::new();
+        let mut singles: Vec<String> = Vec::new();
+        let mut chars = optstring.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c == ':' {
+                continue;
+            }
+
+            if chars.next_if_eq(&':').is_some() {
+                singles.push(c.to_string());
+            } else {
+                flags.push(c.to_string());
+            }
         }
+
+        Self::new()
+            .set_flags(flags.iter().map(|f| f.as_str()).collect())
+            .set_singles(singles.iter().map(|s| s.as_str()).collect())
     }
 
     /// Add flag argument parsing to the `Clappers` config
@@ -356,6 +978,10 @@ impl Clappers {
     /// clappers.set_flags(vec!["h|help", "v|verbose"]);
     ///```
     ///
+    /// Unlike the other argument types, flags can't have a default
+    /// value, so `validate_config()` reports an `arg_spec` with "="
+    /// as `ConfigError::FlagDefault`, and the default is never used.
+    ///
     /// # Return value
     ///
     /// The `Clappers` parser so that it can be chained
@@ -377,7 +1003,7 @@ impl Clappers {
     /// ```
     ///
     pub fn set_flags(mut self, arg_specs: Vec<&str>) -> Self {
-        self.config.flags.add_to_config(arg_specs);
+        self.config.flags.add_to_config(arg_specs, false);
         self
     }
 
@@ -406,6 +1032,13 @@ impl Clappers {
     /// clappers.set_singles(vec!["o|output", "u|username"]);
     ///```
     ///
+    /// An `arg_spec` can end with "=" followed by a default value,
+    /// used when the argument is not supplied on the command line e.g:
+    ///
+    ///```ignore
+    /// clappers.set_singles(vec!["o|output=a.out"]);
+    ///```
+    ///
     /// # Return value
     ///
     /// The `Clappers` parser so that it can be chained
@@ -426,8 +1059,44 @@ impl Clappers {
     /// }
     /// ```
     ///
+    /// Just like getopt, a single value argument in a combination of
+    /// flags takes the rest of the combination as its value:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["v", "f"])
+    ///             .set_singles(vec!["b"])
+    ///     };
+    ///
+    ///     let bundled = clappers().build_from(vec!["prog", "-vbf"]);
+    ///
+    ///     assert!(bundled.get_flag("v"));
+    ///     assert!(!bundled.get_flag("f"));
+    ///     assert_eq!(bundled.get_single("b"), "f");
+    ///
+    ///     let flags = clappers().build_from(vec!["prog", "-vf"]);
+    ///
+    ///     assert!(flags.get_flag("v"));
+    ///     assert!(flags.get_flag("f"));
+    ///     assert_eq!(flags.get_single("b"), "");
+    ///
+    ///     let tar = Clappers::new()
+    ///         .set_flags(vec!["z", "c"])
+    ///         .set_singles(vec!["f"])
+    ///         .build_from(vec!["tar", "-zcf", "filename.tar.gz", "file1"]);
+    ///
+    ///     assert!(tar.get_flag("z") && tar.get_flag("c"));
+    ///     assert_eq!(tar.get_single("f"), "filename.tar.gz");
+    ///     assert_eq!(tar.get_leftovers(), vec!["file1"]);
+    /// }
+    /// ```
+    ///
     pub fn set_singles(mut self, arg_specs: Vec<&str>) -> Self {
-        self.config.singles.add_to_config(arg_specs);
+        self.config.singles.add_to_config(arg_specs, true);
         self
     }
 
@@ -460,6 +1129,12 @@ impl Clappers {
     /// --host host1 host2 hostN
     ///```
     ///
+    /// The first value can also be supplied after an equals sign e.g:
+    ///
+    ///```ignore
+    /// --input=file1.txt file2.txt ... fileN.txt
+    ///```
+    ///
     /// # Parameters
     ///
     /// `arg_specs` specifies which multiple value arguments on the
@@ -472,6 +1147,13 @@ impl Clappers {
     /// clappers.set_multiples(vec!["i|input", "host"]);
     ///```
     ///
+    /// An `arg_spec` can end with "=" followed by a default value,
+    /// used when the argument is not supplied on the command line e.g:
+    ///
+    ///```ignore
+    /// clappers.set_multiples(vec!["host=localhost"]);
+    ///```
+    ///
     /// # Return value
     ///
     /// The `Clappers` parser so that it can be chained
@@ -492,26 +1174,5531 @@ impl Clappers {
     /// }
     /// ```
     ///
+    /// A value after an equals sign is the first value, followed by
+    /// any values after it:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["x"])
+    ///         .set_multiples(vec!["i|input"])
+    ///         .build_from(vec!["prog", "--input=a", "b", "c", "-x", "file1"]);
+    ///
+    ///     assert_eq!(clappers.get_multiple("input"), vec!["a", "b", "c"]);
+    ///     assert!(clappers.get_flag("x"));
+    ///     assert_eq!(clappers.get_leftovers(), vec!["file1"]);
+    /// }
+    /// ```
+    ///
     pub fn set_multiples(mut self, arg_specs: Vec<&str>) -> Self {
-        self.config.multiples.add_to_config(arg_specs);
+        self.config.multiples.add_to_config(arg_specs, true);
         self
     }
 
-    /// Build the command line arguments parser with the current `Clappers` config
+    /// Add multiple value arguments whose values can be wrapped in brackets to the `Clappers` config
     ///
-    /// # Parameters
+    /// Bracketed arguments are multiple value arguments that also take
+    /// every value between an opening and closing bracket, even values
+    /// that look like arguments e.g:
     ///
-    /// None
+    ///```ignore
+    /// --filter [ name -type f ]
+    ///```
     ///
-    /// # Return value
+    /// Brackets can be nested, with the inner brackets kept as values.
+    /// Without an opening bracket, the argument takes its values like
+    /// any other multiple value argument.
     ///
-    /// The `Clappers` parser containing the parsed command line
+    /// Use `try_build()` to catch an opening bracket that is never
+    /// closed, otherwise the values run until the end of the command
+    /// line.
+    ///
+    /// # Parameters
+    ///
+    /// `arg_specs` specifies which bracketed arguments on the command
+    /// line to care about, in the same format as `set_multiples()`,
+    /// including an optional default value e.g "f|filter=name".
+    ///
+    /// `open` is the opening bracket e.g "[".
+    ///
+    /// `close` is the closing bracket e.g "]".
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["v|verbose"])
+    ///             .set_bracketed(vec!["f|filter"], "[", "]")
+    ///     };
+    ///
+    ///     let parsed = clappers()
+    ///         .try_build_from(vec!["find", "--filter", "[", "name", "-type", "f", "]", "-v", "dir"])
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(parsed.get_multiple("filter"), vec!["name", "-type", "f"]);
+    ///     assert!(parsed.get_flag("verbose"));
+    ///     assert_eq!(parsed.get_leftovers(), vec!["dir"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .try_build_from(vec!["find", "-f", "[", "name", "-v"])
+    ///             .unwrap_err(),
+    ///         ParseError::UnbalancedBracket { argument: "f".to_string() }
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn set_bracketed(mut self, arg_specs: Vec<&str>, open: &str, close: &str) -> Self {
+        let configured = self.config.multiples.specs.len();
+
+        self.config.multiples.add_to_config(arg_specs, true);
+
+        for spec in &self.config.multiples.specs[configured..] {
+            self.config
+                .bracketed
+                .insert(spec[0].to_string(), (open.to_string(), close.to_string()));
+        }
+
+        self
+    }
+
+    /// Add key-value arguments to the `Clappers` config
+    ///
+    /// Key-value arguments are multiple value arguments whose values
+    /// are "KEY=VALUE" pairs, retrieved as a map with `get_keyval()`
+    /// e.g:
+    ///
+    ///```ignore
+    /// -D DEBUG=1 -D VERSION=2.0
+    /// -D DEBUG=1 VERSION=2.0
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `arg_specs` specifies which key-value arguments on the command
+    /// line to care about, in the same format as `set_multiples()`,
+    /// including an optional default value, which is everything after
+    /// the first "=" e.g "D|define=DEBUG=1".
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_keyvals(vec!["D|define"])
+    ///         .build();
+    ///
+    ///     for (key, value) in clappers.get_keyval("define") {
+    ///         println!("#define {key} {value}");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// With a default value:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_keyvals(vec!["D|define=DEBUG=1"])
+    ///         .build_from(vec!["prog"]);
+    ///
+    ///     assert_eq!(clappers.get_keyval("define")["DEBUG"], "1");
+    /// }
+    /// ```
+    ///
+    pub fn set_keyvals(mut self, arg_specs: Vec<&str>) -> Self {
+        self.config.multiples.add_to_config(arg_specs, true);
+        self
+    }
+
+    /// Add a single argument with all of its attributes to the `Clappers` config
+    ///
+    /// This is an alternative to configuring arguments in batches with
+    /// `set_flags()`, `set_singles()` and `set_multiples()`, followed
+    /// by their attributes.
+    ///
+    /// # Parameters
+    ///
+    /// `builder` is the argument, built with `ArgBuilder`
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{ArgBuilder, ArgKind, Clappers, ParseError, ValueSource};
+    /// use std::env;
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .arg(
+    ///                 ArgBuilder::new("c|colour")
+    ///                     .kind(ArgKind::Single)
+    ///                     .describe("When to use colour")
+    ///                     .default("auto")
+    ///                     .required()
+    ///                     .possible_values(vec!["auto", "always", "never"]),
+    ///             )
+    ///             .arg(
+    ///                 ArgBuilder::new("v|verbose")
+    ///                     .describe("Print more")
+    ///                     .env("VERBOSE"),
+    ///             )
+    ///             .arg(ArgBuilder::new("i").kind(ArgKind::Multiple))
+    ///             .arg(
+    ///                 ArgBuilder::new("u|user")
+    ///                     .kind(ArgKind::Single)
+    ///                     .env("ARG_EXAMPLE_USER"),
+    ///             )
+    ///     };
+    ///
+    ///     env::set_var("ARG_EXAMPLE_USER", "alfie");
+    ///
+    ///     let parsed = clappers()
+    ///         .try_build_from(vec!["prog", "--colour", "never", "-i", "a.c", "b.c"])
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(parsed.get_single("c"), "never");
+    ///     assert!(!parsed.get_flag("verbose"));
+    ///     assert_eq!(parsed.get_multiple("i"), vec!["a.c", "b.c"]);
+    ///     assert_eq!(parsed.get_single("user"), "alfie");
+    ///     assert_eq!(parsed.values_source("user"), ValueSource::Environment);
+    ///
+    ///     let defaulted = clappers().try_build_from(vec!["prog"]).unwrap();
+    ///
+    ///     assert_eq!(defaulted.get_single("colour"), "auto");
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .try_build_from(vec!["prog", "--colour", "sometimes"])
+    ///             .unwrap_err(),
+    ///         ParseError::InvalidValue {
+    ///             argument: "c".to_string(),
+    ///             value: "sometimes".to_string(),
+    ///         }
+    ///     );
+    ///
+    ///     assert_eq!(
+    ///         parsed.usage(),
+    ///         "usage: prog [arguments]
+    ///
+    /// Flags:
+    ///     -v|--verbose               Print more [env: VERBOSE]
+    ///
+    /// Single value arguments:
+    ///     -c|--colour <value>        When to use colour [default: auto]
+    ///     -u|--user <value>          [env: ARG_EXAMPLE_USER]
+    ///
+    /// Multiple value arguments:
+    ///     -i <value1> ... <valueN>
+    /// "
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn arg(mut self, builder: ArgBuilder) -> Self {
+        let spec = match &builder.default {
+            Some(default) => format!("{}={default}", builder.spec),
+            None => builder.spec.to_string(),
+        };
+
+        self = match builder.kind {
+            ArgKind::Flag => self.set_flags(vec![&spec]),
+            ArgKind::Single => self.set_singles(vec![&spec]),
+            ArgKind::Multiple => self.set_multiples(vec![&spec]),
+        };
+
+        // any alias will do, as attributes are matched to the argument by alias
+        let argument = match builder.spec.split('|').find(|alias| !alias.is_empty()) {
+            Some(argument) => argument.to_string(),
+            None => return self,
+        };
+
+        if let Some(description) = &builder.description {
+            self = self.describe(&argument, description);
+        }
+
+        if builder.required {
+            self = self.required(vec![&argument]);
+        }
+
+        if let Some(values) = &builder.possible_values {
+            self = self.possible_values(&argument, values.iter().map(|v| v.as_str()).collect());
+        }
+
+        if let Some(var) = &builder.env {
+            self = match builder.kind {
+                ArgKind::Flag => self.flag_env(&argument, var),
+                ArgKind::Single | ArgKind::Multiple => self.value_env(&argument, var),
+            };
+        }
+
+        self
+    }
+
+    /// Add a subcommand with its own separate `Clappers` parser to the `Clappers` config
+    ///
+    /// The first value on the command line matching `name` hands all
+    /// remaining command line arguments over to `subcommand`, which
+    /// parses them as though `name` was its own `argv[0]` e.g:
+    ///
+    ///```ignore
+    /// apt-get -y install -f cargo
+    /// apt-get update -f
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `name` is the name of the subcommand on the command line.
+    ///
+    /// `subcommand` is an unbuilt `Clappers` parser configured with
+    /// the subcommand's own arguments.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["y|yes"])
+    ///         .set_subcommand("install", Clappers::new().set_flags(vec!["f|fix-broken"]))
+    ///         .set_subcommand("update", Clappers::new())
+    ///         .build_from(vec!["apt-get", "-y", "install", "-f", "cargo"]);
+    ///
+    ///     let (name, install) = clappers.get_subcommand().unwrap();
+    ///
+    ///     assert!(clappers.get_flag("yes"));
+    ///     assert_eq!(name, "install");
+    ///     assert!(install.get_flag("fix-broken"));
+    ///     assert_eq!(install.get_leftovers(), vec!["cargo"]);
+    /// }
+    /// ```
+    ///
+    pub fn set_subcommand(mut self, name: &str, subcommand: Clappers) -> Self {
+        self.config.subcommands.insert(name.to_string(), subcommand);
+        self
+    }
+
+    /// Require arguments to be supplied
+    ///
+    /// Checked by `try_build()`, which returns
+    /// `ParseError::MissingRequired` for the first argument without a
+    /// value. Default values and environment variables count as
+    /// supplied.
+    ///
+    /// # Parameters
+    ///
+    /// `arguments` are any aliases of arguments of any type.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_singles(vec!["o|output", "u|username"])
+    ///             .required(vec!["output"])
+    ///     };
+    ///
+    ///     assert!(clappers().try_build_from(vec!["prog", "-o", "a.out"]).is_ok());
+    ///
+    ///     assert_eq!(
+    ///         clappers().try_build_from(vec!["prog", "-u", "alfie"]).unwrap_err(),
+    ///         ParseError::MissingRequired {
+    ///             argument: "o".to_string()
+    ///         }
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn required(mut self, arguments: Vec<&str>) -> Self {
+        self.config
+            .required
+            .extend(arguments.iter().map(|argument| argument.to_string()));
+        self
+    }
+
+    /// Restrict the values of an argument to a set of possible values
+    ///
+    /// Checked by `try_build()`, which returns
+    /// `ParseError::InvalidValue` for any other value.
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a single or multiple value argument.
+    ///
+    /// `values` are the possible values.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_singles(vec!["color"])
+    ///             .possible_values("color", vec!["auto", "always", "never"])
+    ///     };
+    ///
+    ///     assert!(clappers().try_build_from(vec!["prog", "--color", "never"]).is_ok());
+    ///
+    ///     assert_eq!(
+    ///         clappers().try_build_from(vec!["prog", "--color", "blue"]).unwrap_err(),
+    ///         ParseError::InvalidValue {
+    ///             argument: "color".to_string(),
+    ///             value: "blue".to_string(),
+    ///         }
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn possible_values(mut self, argument: &str, values: Vec<&str>) -> Self {
+        self.config.possible_values.push((
+            argument.to_string(),
+            values.iter().map(|value| value.to_string()).collect(),
+        ));
+        self
+    }
+
+    /// Require values of an argument to be at least `n` characters long
+    ///
+    /// Checked by `try_build()`, which returns
+    /// `ParseError::InvalidValue` for any shorter value. When several
+    /// arguments break their constraints, the error is for the first
+    /// one configured.
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a single or multiple value argument.
+    ///
+    /// `n` is the minimum number of characters of each value.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let result = Clappers::new()
+    ///         .set_singles(vec!["u|username"])
+    ///         .min_length("username", 3)
+    ///         .try_build_from(vec!["prog", "-u", "al"]);
+    ///
+    ///     assert_eq!(
+    ///         result.unwrap_err(),
+    ///         ParseError::InvalidValue {
+    ///             argument: "u".to_string(),
+    ///             value: "al".to_string(),
+    ///         }
+    ///     );
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["u|username"])
+    ///         .min_length("username", 3)
+    ///         .try_build_from(vec!["prog", "-u", "alfie"])
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(clappers.get_single("username"), "alfie");
+    ///
+    ///     // the first limit configured is reported, not the first argument supplied
+    ///     let result = Clappers::new()
+    ///         .set_singles(vec!["u|username", "p|password", "h|host"])
+    ///         .min_length("password", 8)
+    ///         .min_length("username", 3)
+    ///         .min_length("host", 4)
+    ///         .try_build_from(vec!["prog", "-u", "al", "-p", "hunter2", "-h", "h"]);
+    ///
+    ///     assert_eq!(
+    ///         result.unwrap_err(),
+    ///         ParseError::InvalidValue {
+    ///             argument: "p".to_string(),
+    ///             value: "hunter2".to_string(),
+    ///         }
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn min_length(mut self, argument: &str, n: usize) -> Self {
+        set_limit(&mut self.config.min_lengths, argument, n);
+        self
+    }
+
+    /// Require values of an argument to be at most `n` characters long
+    ///
+    /// Checked by `try_build()`, which returns
+    /// `ParseError::InvalidValue` for any longer value.
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a single or multiple value argument.
+    ///
+    /// `n` is the maximum number of characters of each value.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let result = Clappers::new()
+    ///         .set_multiples(vec!["u|user"])
+    ///         .max_length("user", 5)
+    ///         .try_build_from(vec!["prog", "-u", "alfie", "zelensky"]);
+    ///
+    ///     assert_eq!(
+    ///         result.unwrap_err(),
+    ///         ParseError::InvalidValue {
+    ///             argument: "u".to_string(),
+    ///             value: "zelensky".to_string(),
+    ///         }
+    ///     );
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["u|user"])
+    ///         .max_length("user", 5)
+    ///         .try_build_from(vec!["prog", "-u", "alfie", "john"])
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(clappers.get_multiple("user"), vec!["alfie", "john"]);
+    /// }
+    /// ```
+    ///
+    pub fn max_length(mut self, argument: &str, n: usize) -> Self {
+        set_limit(&mut self.config.max_lengths, argument, n);
+        self
+    }
+
+    /// Require a multiple value argument to have exactly `n` values when supplied
+    ///
+    /// Values are read as usual, then checked by `try_build()`, which
+    /// returns `ParseError::WrongValueCount` for any other number of
+    /// values e.g:
+    ///
+    ///```ignore
+    /// --rgb 255 128 0
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a multiple value argument.
+    ///
+    /// `n` is the number of values.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_multiples(vec!["rgb"])
+    ///             .exact_values("rgb", 3)
+    ///     };
+    ///
+    ///     let rgb = clappers()
+    ///         .try_build_from(vec!["prog", "--rgb", "255", "128", "0"])
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(rgb.get_multiple("rgb"), vec!["255", "128", "0"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .try_build_from(vec!["prog", "--rgb", "255", "128"])
+    ///             .unwrap_err(),
+    ///         ParseError::WrongValueCount {
+    ///             argument: "rgb".to_string(),
+    ///             expected: 3,
+    ///             found: 2,
+    ///         }
+    ///     );
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .try_build_from(vec!["prog", "--rgb", "255", "128", "0", "64"])
+    ///             .unwrap_err(),
+    ///         ParseError::WrongValueCount {
+    ///             argument: "rgb".to_string(),
+    ///             expected: 3,
+    ///             found: 4,
+    ///         }
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn exact_values(mut self, argument: &str, n: usize) -> Self {
+        set_limit(&mut self.config.exact_values, argument, n);
+        self
+    }
+
+    /// Reject empty values supplied on the command line for single or multiple value arguments
+    ///
+    /// Empty values are allowed by default, and can be supplied by
+    /// quoting or after an equals sign e.g:
+    ///
+    ///```ignore
+    /// --name ""
+    /// --name=
+    ///```
+    ///
+    /// Empty values are only rejected by `try_build()`.
+    ///
+    /// # Parameters
+    ///
+    /// `arguments` is a list of aliases of single or multiple value
+    /// arguments.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_singles(vec!["n|name", "comment"])
+    ///             .reject_empty(vec!["name"])
+    ///     };
+    ///
+    ///     for args in [vec!["prog", "--name", ""], vec!["prog", "--name="]] {
+    ///         assert_eq!(
+    ///             clappers().try_build_from(args).unwrap_err(),
+    ///             ParseError::EmptyValue { argument: "n".to_string() }
+    ///         );
+    ///     }
+    ///
+    ///     let allowed = clappers()
+    ///         .try_build_from(vec!["prog", "--name", "zelensky", "--comment", ""])
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(allowed.get_single_opt("comment"), Some("".to_string()));
+    /// }
+    /// ```
+    ///
+    pub fn reject_empty(mut self, arguments: Vec<&str>) -> Self {
+        self.config
+            .reject_empty
+            .extend(arguments.iter().map(|argument| argument.to_string()));
+        self
+    }
+
+    /// Choose how values are combined when a multiple value argument is supplied more than once
+    ///
+    /// By default, values are appended in the order they were supplied
+    /// on the command line.
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a multiple value argument.
+    ///
+    /// `policy` is how values from each occurrence are combined:
+    ///
+    /// - `MergePolicy::Append` keeps every value
+    /// - `MergePolicy::Replace` keeps only the values of the last occurrence
+    /// - `MergePolicy::Unique` keeps every value, skipping repeated values
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, MergePolicy};
+    ///
+    /// fn main() {
+    ///     let clappers = |policy| {
+    ///         Clappers::new()
+    ///             .set_multiples(vec!["i|input"])
+    ///             .multiples_merge("input", policy)
+    ///             .build_from(vec!["prog", "-i", "a", "b", "-i", "c", "a"])
+    ///     };
+    ///
+    ///     assert_eq!(
+    ///         clappers(MergePolicy::Append).get_multiple("i"),
+    ///         vec!["a", "b", "c", "a"]
+    ///     );
+    ///     assert_eq!(clappers(MergePolicy::Replace).get_multiple("i"), vec!["c", "a"]);
+    ///     assert_eq!(clappers(MergePolicy::Unique).get_multiple("i"), vec!["a", "b", "c"]);
+    /// }
+    /// ```
+    ///
+    pub fn multiples_merge(mut self, argument: &str, policy: MergePolicy) -> Self {
+        self.config
+            .multiples_merge
+            .push((argument.to_string(), policy));
+        self
+    }
+
+    /// Read the values of a multiple value argument from stdin when none are supplied on the command line
+    ///
+    /// When the argument is supplied without any values, each line of
+    /// stdin becomes a value, just like `xargs` e.g:
+    ///
+    ///```text
+    /// find . -name '*.c' | cc --input
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a multiple value argument.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["i|input"])
+    ///         .from_stdin("input")
+    ///         .build();
+    ///
+    ///     let input_filenames = clappers.get_multiple("input");
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    pub fn from_stdin(mut self, argument: &str) -> Self {
+        self.config.from_stdin.insert(argument.to_string());
+        self
+    }
+
+    /// Fall back to an environment variable for a flag argument
+    ///
+    /// If the flag was not supplied on the command line, it is `true`
+    /// when the environment variable is set to one of `1`, `true`,
+    /// `yes` or `on` (ignoring case), and `false` otherwise.
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a flag argument.
+    ///
+    /// `var` is the name of the environment variable.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    /// use std::env;
+    ///
+    /// fn main() {
+    ///     env::set_var("FLAG_ENV_VERBOSE", "yes");
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .flag_env("verbose", "FLAG_ENV_VERBOSE")
+    ///         .build_from(vec!["prog"]);
+    ///
+    ///     assert!(clappers.get_flag("verbose"));
+    ///
+    ///     env::set_var("FLAG_ENV_VERBOSE", "0");
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .flag_env("verbose", "FLAG_ENV_VERBOSE")
+    ///         .build_from(vec!["prog"]);
+    ///
+    ///     assert!(!clappers.get_flag("verbose"));
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .flag_env("verbose", "FLAG_ENV_VERBOSE")
+    ///         .build_from(vec!["prog", "-v"]);
+    ///
+    ///     assert!(clappers.get_flag("verbose"));
+    /// }
+    /// ```
+    ///
+    pub fn flag_env(mut self, argument: &str, var: &str) -> Self {
+        self.config
+            .flag_envs
+            .insert(argument.to_string(), var.to_string());
+        self
+    }
+
+    /// Fall back to an environment variable for a single or multiple value argument
+    ///
+    /// If the argument was not supplied on the command line at all,
+    /// its value is the environment variable's value when it is set.
+    /// A multiple value argument gets the whole value as its only
+    /// value. The environment takes precedence over any default value.
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a single or multiple value argument.
+    ///
+    /// `var` is the name of the environment variable.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ValueSource};
+    /// use std::env;
+    ///
+    /// fn main() {
+    ///     env::set_var("VALUE_ENV_OUTPUT", "env.out");
+    ///     env::set_var("VALUE_ENV_INPUT", "env.c");
+    ///     env::remove_var("VALUE_ENV_USERNAME");
+    ///
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_singles(vec!["o|output=a.out", "u|username"])
+    ///             .set_multiples(vec!["i|input"])
+    ///             .value_env("output", "VALUE_ENV_OUTPUT")
+    ///             .value_env("username", "VALUE_ENV_USERNAME")
+    ///             .value_env("input", "VALUE_ENV_INPUT")
+    ///     };
+    ///
+    ///     let parsed = clappers().build_from(vec!["prog"]);
+    ///
+    ///     assert_eq!(parsed.get_single("output"), "env.out");
+    ///     assert_eq!(parsed.values_source("output"), ValueSource::Environment);
+    ///     assert_eq!(parsed.get_multiple("input"), vec!["env.c"]);
+    ///     assert_eq!(parsed.values_source("input"), ValueSource::Environment);
+    ///     assert_eq!(parsed.get_single_opt("username"), None);
+    ///
+    ///     let parsed = clappers().build_from(vec!["prog", "-o", "b.out", "-i", "a.c", "b.c"]);
+    ///
+    ///     assert_eq!(parsed.get_single("output"), "b.out");
+    ///     assert_eq!(parsed.values_source("output"), ValueSource::CommandLine);
+    ///     assert_eq!(parsed.get_multiple("input"), vec!["a.c", "b.c"]);
+    /// }
+    /// ```
+    ///
+    pub fn value_env(mut self, argument: &str, var: &str) -> Self {
+        self.config
+            .value_envs
+            .push((argument.to_string(), var.to_string()));
+        self
+    }
+
+    /// Require all of the specified arguments whenever an argument is supplied
+    ///
+    /// Checked by `try_build()`, which returns
+    /// `ParseError::RequiresAll` naming the missing arguments.
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of an argument of any type.
+    ///
+    /// `needs` are aliases of the arguments that must all be supplied
+    /// alongside `argument`.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["tls"])
+    ///             .set_singles(vec!["cert", "key"])
+    ///             .requires_all("tls", vec!["cert", "key"])
+    ///     };
+    ///
+    ///     assert!(clappers()
+    ///         .try_build_from(vec!["prog", "--tls", "--cert", "a.pem", "--key", "a.key"])
+    ///         .is_ok());
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .try_build_from(vec!["prog", "--tls", "--cert", "a.pem"])
+    ///             .unwrap_err(),
+    ///         ParseError::RequiresAll {
+    ///             argument: "tls".to_string(),
+    ///             missing: vec!["key".to_string()],
+    ///         }
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn requires_all(mut self, argument: &str, needs: Vec<&str>) -> Self {
+        self.config.requires_all.push((
+            argument.to_string(),
+            needs.iter().map(|need| need.to_string()).collect(),
+        ));
+        self
+    }
+
+    /// Require at least one of the specified arguments whenever an argument is supplied
+    ///
+    /// Checked by `try_build()`, which returns
+    /// `ParseError::RequiresAny` naming the arguments of which none
+    /// were supplied.
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of an argument of any type.
+    ///
+    /// `needs` are aliases of the arguments of which at least one
+    /// must be supplied alongside `argument`.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["login"])
+    ///             .set_singles(vec!["password", "token"])
+    ///             .requires_any("login", vec!["password", "token"])
+    ///     };
+    ///
+    ///     assert!(clappers()
+    ///         .try_build_from(vec!["prog", "--login", "--token", "abc"])
+    ///         .is_ok());
+    ///
+    ///     assert_eq!(
+    ///         clappers().try_build_from(vec!["prog", "--login"]).unwrap_err(),
+    ///         ParseError::RequiresAny {
+    ///             argument: "login".to_string(),
+    ///             needs: vec!["password".to_string(), "token".to_string()],
+    ///         }
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn requires_any(mut self, argument: &str, needs: Vec<&str>) -> Self {
+        self.config.requires_any.push((
+            argument.to_string(),
+            needs.iter().map(|need| need.to_string()).collect(),
+        ));
+        self
+    }
+
+    /// Describe an argument in the help text
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of an argument of any type.
+    ///
+    /// `description` is what the argument does.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["h|help", "v|verbose"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .describe("help", "Print this help")
+    ///         .describe("o", "Output filename")
+    ///         .build_from(vec!["prog"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers.usage(),
+    ///         "usage: prog [arguments]
+    ///
+    /// Flags:
+    ///     -h|--help             Print this help
+    ///     -v|--verbose
+    ///
+    /// Single value arguments:
+    ///     -o|--output <value>   Output filename
+    /// "
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn describe(mut self, argument: &str, description: &str) -> Self {
+        self.config
+            .descriptions
+            .insert(argument.to_string(), description.to_string());
+        self
+    }
+
+    /// Set the width of the help text, wrapping descriptions to fit
+    ///
+    /// The default width is 80 columns.
+    ///
+    /// # Parameters
+    ///
+    /// `columns` is the maximum line width, although long words and
+    /// aliases are never broken.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["v|verbose"])
+    ///             .describe("verbose", "Print every file as it is processed")
+    ///     };
+    ///
+    ///     assert_eq!(
+    ///         clappers().build_from(vec!["prog"]).usage(),
+    ///         "usage: prog [arguments]
+    ///
+    /// Flags:
+    ///     -v|--verbose   Print every file as it is processed
+    /// "
+    ///     );
+    ///
+    ///     assert_eq!(
+    ///         clappers().usage_width(40).build_from(vec!["prog"]).usage(),
+    ///         "usage: prog [arguments]
+    ///
+    /// Flags:
+    ///     -v|--verbose   Print every file as
+    ///                    it is processed
+    /// "
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn usage_width(mut self, columns: usize) -> Self {
+        self.config.usage_width = columns;
+        self
+    }
+
+    /// Name the leftovers in generated help text
+    ///
+    /// The default name is "ARGS...". Once named, `usage()` also lists
+    /// the leftovers in their own section.
+    ///
+    /// # Parameters
+    ///
+    /// `name` is the placeholder shown for leftovers e.g "FILES...".
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["l"])
+    ///         .leftovers_metavar("FILES...")
+    ///         .build_from(vec!["ls"]);
+    ///
+    ///     assert_eq!(clappers.synopsis(), "usage: ls [-l] [FILES...]");
+    ///     assert_eq!(
+    ///         clappers.usage(),
+    ///         "usage: ls [arguments] [FILES...]
+    ///
+    /// Flags:
+    ///     -l
+    ///
+    /// Leftovers:
+    ///     FILES...
+    /// "
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn leftovers_metavar(mut self, name: &str) -> Self {
+        self.config.leftovers_metavar = Some(name.to_string());
+        self
+    }
+
+    /// Describe the leftovers in generated help text
+    ///
+    /// Once described, `usage()` lists the leftovers in their own
+    /// section, just like any other argument.
+    ///
+    /// # Parameters
+    ///
+    /// `text` is a short description of what the leftovers are for.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["l"])
+    ///         .describe("l", "Use a long listing format")
+    ///         .leftovers_metavar("FILES...")
+    ///         .leftovers_description("Files to list")
+    ///         .build_from(vec!["ls"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers.usage(),
+    ///         "usage: ls [arguments] [FILES...]
+    ///
+    /// Flags:
+    ///     -l         Use a long listing format
+    ///
+    /// Leftovers:
+    ///     FILES...   Files to list
+    /// "
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn leftovers_description(mut self, text: &str) -> Self {
+        self.config.leftovers_description = Some(text.to_string());
+        self
+    }
+
+    /// Describe what the program does in a single line
+    ///
+    /// Used by `generate_manpage()` for the NAME section.
+    ///
+    /// # Parameters
+    ///
+    /// `text` is a short description of the program.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["l"])
+    ///         .about("list directory contents")
+    ///         .build_from(vec!["ls"]);
+    ///
+    ///     assert!(clappers
+    ///         .generate_manpage("ls", 1)
+    ///         .contains(".SH NAME\nls \\- list directory contents\n"));
+    /// }
+    /// ```
+    ///
+    pub fn about(mut self, text: &str) -> Self {
+        self.config.about = Some(text.to_string());
+        self
+    }
+
+    /// Check the `Clappers` config for mistakes
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// `Ok` if the config is consistent, and the first `ConfigError`
+    /// found otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ConfigError};
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["help|h"])
+    ///         .set_singles(vec!["help"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers.validate_config(),
+    ///         Err(ConfigError::DuplicateCanonical {
+    ///             name: "help".to_string()
+    ///         })
+    ///     );
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["help|h"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .set_multiples(vec!["i|input"]);
+    ///
+    ///     assert_eq!(clappers.validate_config(), Ok(()));
+    ///
+    ///     let clappers = Clappers::new().set_flags(vec!["v|verbose=true"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers.validate_config(),
+    ///         Err(ConfigError::FlagDefault {
+    ///             name: "v".to_string()
+    ///         })
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn validate_config(&self) -> Result<(), ConfigError> {
+        let mut seen: HashSet<&String> = HashSet::new();
+
+        for config_type in [
+            &self.config.flags,
+            &self.config.singles,
+            &self.config.multiples,
+        ] {
+            let names: HashSet<&String> = config_type.specs.iter().map(|spec| &spec[0]).collect();
+
+            if let Some(name) = config_type
+                .specs
+                .iter()
+                .map(|spec| &spec[0])
+                .find(|name| seen.contains(name))
+            {
+                return Err(ConfigError::DuplicateCanonical {
+                    name: name.to_string(),
+                });
+            }
+
+            seen.extend(names);
+        }
+
+        if let Some(name) = self.config.flags.misplaced_defaults.first() {
+            return Err(ConfigError::FlagDefault {
+                name: name.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Require a group of arguments to be supplied together, or not at all
+    ///
+    /// Checked by `try_build()`, which returns
+    /// `ParseError::UnmetRequirement` naming the missing arguments
+    /// when only some of the group were supplied.
+    ///
+    /// # Parameters
+    ///
+    /// `group` are aliases of the arguments that go together.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_singles(vec!["cert", "key"])
+    ///             .together(vec!["cert", "key"])
+    ///     };
+    ///
+    ///     assert!(clappers()
+    ///         .try_build_from(vec!["prog", "--cert", "a.pem", "--key", "a.key"])
+    ///         .is_ok());
+    ///
+    ///     assert!(clappers().try_build_from(vec!["prog"]).is_ok());
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .try_build_from(vec!["prog", "--key", "a.key"])
+    ///             .unwrap_err(),
+    ///         ParseError::UnmetRequirement {
+    ///             group: vec!["cert".to_string(), "key".to_string()],
+    ///             missing: vec!["cert".to_string()],
+    ///         }
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn together(mut self, group: Vec<&str>) -> Self {
+        self.config
+            .together
+            .push(group.iter().map(|argument| argument.to_string()).collect());
+        self
+    }
+
+    /// Make a flag argument end argument parsing, just like a double-dash
+    ///
+    /// The flag is set as usual, and everything after it on the
+    /// command line becomes a trailing leftover e.g:
+    ///
+    ///```ignore
+    /// cargo run --bin x --args -v input.txt
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a flag argument.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["args", "v|verbose"])
+    ///         .set_singles(vec!["bin"])
+    ///         .terminator_flag("args")
+    ///         .build_from(vec!["cargo", "--bin", "x", "file1", "--args", "-v", "input.txt"]);
+    ///
+    ///     assert!(clappers.get_flag("args"));
+    ///     assert!(!clappers.get_flag("verbose"));
+    ///     assert_eq!(clappers.get_trailing(), vec!["-v", "input.txt"]);
+    ///     assert_eq!(clappers.get_leftovers(), vec!["file1", "-v", "input.txt"]);
+    /// }
+    /// ```
+    ///
+    pub fn terminator_flag(mut self, argument: &str) -> Self {
+        self.config.terminator_flags.insert(argument.to_string());
+        self
+    }
+
+    /// Choose which markers end argument parsing, in place of the double-dash
+    ///
+    /// By default, only the double-dash is a terminator. Everything
+    /// after any of these markers becomes a trailing leftover.
+    ///
+    /// # Parameters
+    ///
+    /// `markers` is the list of terminators, replacing the double-dash
+    /// unless it's included.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["v|verbose"])
+    ///             .terminators(vec!["--", "---args"])
+    ///     };
+    ///
+    ///     for marker in ["--", "---args"] {
+    ///         let clappers = clappers().build_from(vec!["run", "-v", "file1", marker, "-v", "file2"]);
+    ///
+    ///         assert!(clappers.get_flag("verbose"));
+    ///         assert_eq!(clappers.get_trailing(), vec!["-v", "file2"]);
+    ///         assert_eq!(clappers.get_leftovers(), vec!["file1", "-v", "file2"]);
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub fn terminators(mut self, markers: Vec<&str>) -> Self {
+        self.config.terminators = markers.iter().map(|marker| marker.to_string()).collect();
+        self
+    }
+
+    /// Make flag arguments stop argument parsing immediately, ignoring the rest of the command line
+    ///
+    /// Useful for flags like `--help` and `--version`, where the rest of
+    /// the command line doesn't matter. `try_build()` also skips checking
+    /// the command line once one of these flags is seen, so they work even
+    /// amongst otherwise invalid arguments
+    ///
+    /// # Parameters
+    ///
+    /// `arguments` is a list of aliases of flag arguments.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["h|help", "V|version"])
+    ///         .set_singles(vec!["color"])
+    ///         .required(vec!["color"])
+    ///         .possible_values("color", vec!["auto", "never"])
+    ///         .short_circuit_on(vec!["help", "version"])
+    ///         .try_build_from(vec!["prog", "file1", "--help", "--color", "bogus", "file2"])
+    ///         .unwrap();
+    ///
+    ///     assert!(clappers.get_flag("help"));
+    ///     assert_eq!(clappers.get_single("color"), "");
+    ///     assert_eq!(clappers.get_leftovers(), vec!["file1"]);
+    /// }
+    /// ```
+    ///
+    pub fn short_circuit_on(mut self, arguments: Vec<&str>) -> Self {
+        self.config
+            .short_circuits
+            .extend(arguments.iter().map(|argument| argument.to_string()));
+        self
+    }
+
+    /// Allow flag arguments to be unset on the command line with a "no-" prefix
+    ///
+    /// A negatable flag is `false` when its last occurrence on the
+    /// command line is prefixed with "no-", even if it was set earlier
+    /// or by `flag_env()` e.g:
+    ///
+    ///```ignore
+    /// --verbose --no-verbose
+    ///```
+    ///
+    /// Flags that are not negatable ignore "no-" prefixed aliases.
+    ///
+    /// # Parameters
+    ///
+    /// `arguments` are any aliases of flag arguments.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose", "c|color"])
+    ///         .negatable(vec!["color"])
+    ///         .build_from(vec!["prog", "--verbose", "--no-verbose", "--color", "--no-color"]);
+    ///
+    ///     assert!(clappers.get_flag("verbose"));
+    ///     assert!(!clappers.get_flag("color"));
+    /// }
+    /// ```
+    ///
+    pub fn negatable(mut self, arguments: Vec<&str>) -> Self {
+        self.config
+            .negatable
+            .extend(arguments.iter().map(|argument| argument.to_string()));
+        self
+    }
+
+    /// Set the order of argument types in the help text
+    ///
+    /// The default order is flags, single value arguments, then
+    /// multiple value arguments. Argument types missing from `order`
+    /// are left out of the help text.
+    ///
+    /// # Parameters
+    ///
+    /// `order` is the argument types in the order their sections
+    /// appear in the help text.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{ArgKind, Clappers};
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["h|help"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .set_multiples(vec!["i|input"])
+    ///         .usage_order(vec![ArgKind::Single, ArgKind::Multiple, ArgKind::Flag])
+    ///         .build_from(vec!["prog"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers.usage(),
+    ///         "usage: prog [arguments]
+    ///
+    /// Single value arguments:
+    ///     -o|--output <value>
+    ///
+    /// Multiple value arguments:
+    ///     -i|--input <value1> ... <valueN>
+    ///
+    /// Flags:
+    ///     -h|--help
+    /// "
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn usage_order(mut self, order: Vec<ArgKind>) -> Self {
+        self.config.usage_order = order;
+        self
+    }
+
+    /// Ignore a specific value if it is the first command line argument after the program name
+    ///
+    /// This suits tools run as cargo subcommands, as cargo runs
+    /// `cargo-foo foo --verbose` for `cargo foo --verbose`
+    ///
+    /// # Parameters
+    ///
+    /// `token` is the value to ignore.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .skip_leading("foo")
+    ///         .build_from(vec!["cargo-foo", "foo", "--verbose", "foo"]);
+    ///
+    ///     assert!(clappers.get_flag("verbose"));
+    ///     assert_eq!(clappers.get_leftovers(), vec!["foo"]);
+    /// }
+    /// ```
+    ///
+    pub fn skip_leading(mut self, token: &str) -> Self {
+        self.config.skip_leading = Some(token.to_string());
+        self
+    }
+
+    /// Allow a single value argument to take a value starting with a dash
+    ///
+    /// By default, a single value argument followed by anything
+    /// starting with a dash is left without a value. With this, the
+    /// following value is taken as long as it is not itself a
+    /// configured argument or the double-dash e.g:
+    ///
+    ///```ignore
+    /// --offset -5
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a single value argument.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .build_from(vec!["prog", "-o", "-notaflag"]);
+    ///
+    ///     assert!(clappers.is_dangling("output"));
+    ///     assert_eq!(clappers.get_single("output"), "");
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .single_consumes_unknown_dash("output")
+    ///         .build_from(vec!["prog", "-o", "-notaflag"]);
+    ///
+    ///     assert_eq!(clappers.get_single("output"), "-notaflag");
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .single_consumes_unknown_dash("output")
+    ///         .build_from(vec!["prog", "-o", "-v"]);
+    ///
+    ///     assert!(clappers.is_dangling("output"));
+    ///     assert!(clappers.get_flag("verbose"));
+    /// }
+    /// ```
+    ///
+    pub fn single_consumes_unknown_dash(mut self, argument: &str) -> Self {
+        self.config
+            .consumes_unknown_dash
+            .insert(argument.to_string());
+        self
+    }
+
+    /// Allow a single value argument to take a terminator as its value
+    ///
+    /// By default, a single value argument followed by a terminator
+    /// such as the double-dash is left without a value, and the
+    /// terminator ends argument parsing as usual. With this, the
+    /// terminator is taken as the value instead e.g:
+    ///
+    ///```ignore
+    /// --separator --
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of a single value argument.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output"])
+    ///         .build_from(vec!["prog", "--output", "--", "file1"]);
+    ///
+    ///     assert!(clappers.is_dangling("output"));
+    ///     assert_eq!(clappers.get_trailing(), vec!["file1"]);
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output"])
+    ///         .single_consumes_terminator("output")
+    ///         .build_from(vec!["prog", "--output", "--", "file1"]);
+    ///
+    ///     assert_eq!(clappers.get_single("output"), "--");
+    ///     assert!(clappers.get_trailing().is_empty());
+    ///     assert_eq!(clappers.get_leftovers(), vec!["file1"]);
+    /// }
+    /// ```
+    ///
+    pub fn single_consumes_terminator(mut self, argument: &str) -> Self {
+        self.config.consumes_terminator.insert(argument.to_string());
+        self
+    }
+
+    /// Disallow leftovers on the command line
+    ///
+    /// Leftovers are still collected, but are rejected by `try_build()`,
+    /// including any values after a double-dash. Like `strict_unknown()`
+    /// and `strict_missing_value()`, this is independent of the other
+    /// strictness settings.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["v|verbose"])
+    ///             .set_singles(vec!["o|output"])
+    ///             .no_leftovers()
+    ///     };
+    ///
+    ///     assert!(clappers()
+    ///         .try_build_from(vec!["prog", "-v", "-o", "a.out"])
+    ///         .is_ok());
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .try_build_from(vec!["prog", "-v", "stray", "-o", "a.out"])
+    ///             .unwrap_err(),
+    ///         ParseError::UnexpectedPositional { value: "stray".to_string() }
+    ///     );
+    ///
+    ///     // unknown arguments and missing values are still allowed
+    ///     assert!(clappers()
+    ///         .try_build_from(vec!["prog", "--colour", "-v", "--output"])
+    ///         .is_ok());
+    /// }
+    /// ```
+    ///
+    pub fn no_leftovers(mut self) -> Self {
+        self.config.no_leftovers = true;
+        self
+    }
+
+    /// Disallow arguments on the command line that aren't in the `Clappers` config
+    ///
+    /// Unknown arguments are still collected, but are rejected by
+    /// `try_build()`.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || Clappers::new().set_flags(vec!["v|verbose"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .strict_unknown()
+    ///             .try_build_from(vec!["prog", "-v", "--colour", "file1"])
+    ///             .unwrap_err(),
+    ///         ParseError::UnknownArgument {
+    ///             argument: "--colour".to_string(),
+    ///             suggestion: None,
+    ///         }
+    ///     );
+    ///
+    ///     // near misses suggest the closest alias
+    ///     let error = clappers()
+    ///         .strict_unknown()
+    ///         .try_build_from(vec!["prog", "--verbsoe"])
+    ///         .unwrap_err();
+    ///
+    ///     assert_eq!(
+    ///         error,
+    ///         ParseError::UnknownArgument {
+    ///             argument: "--verbsoe".to_string(),
+    ///             suggestion: Some("--verbose".to_string()),
+    ///         }
+    ///     );
+    ///     assert_eq!(
+    ///         error.to_string(),
+    ///         "unknown argument `--verbsoe`; did you mean `--verbose`?"
+    ///     );
+    ///
+    ///     // leftovers are still allowed
+    ///     assert!(clappers()
+    ///         .strict_unknown()
+    ///         .try_build_from(vec!["prog", "-v", "file1"])
+    ///         .is_ok());
+    ///
+    ///     assert!(clappers()
+    ///         .try_build_from(vec!["prog", "-v", "--colour", "file1"])
+    ///         .is_ok());
+    /// }
+    /// ```
+    ///
+    pub fn strict_unknown(mut self) -> Self {
+        self.config.strict_unknown = true;
+        self
+    }
+
+    /// Disallow single value arguments on the command line without a value
+    ///
+    /// Single value arguments without a value are still collected, see
+    /// `is_dangling()`, but are rejected by `try_build()`.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["v|verbose"])
+    ///             .set_singles(vec!["o|output"])
+    ///     };
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .strict_missing_value()
+    ///             .try_build_from(vec!["prog", "--output", "-v", "--colour"])
+    ///             .unwrap_err(),
+    ///         ParseError::MissingValue { argument: "o".to_string() }
+    ///     );
+    ///
+    ///     // unknown arguments are still allowed
+    ///     assert!(clappers()
+    ///         .strict_missing_value()
+    ///         .try_build_from(vec!["prog", "--output", "a.out", "--colour"])
+    ///         .is_ok());
+    ///
+    ///     assert!(clappers()
+    ///         .try_build_from(vec!["prog", "--output", "-v"])
+    ///         .is_ok());
+    /// }
+    /// ```
+    ///
+    pub fn strict_missing_value(mut self) -> Self {
+        self.config.strict_missing_value = true;
+        self
+    }
+
+    /// Disallow leftovers on the command line
+    ///
+    /// This is the same as `no_leftovers()`, and is named to match
+    /// `strict_unknown()` and `strict_missing_value()`.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["v|verbose"])
+    ///             .set_singles(vec!["o|output"])
+    ///     };
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .strict_positional()
+    ///             .try_build_from(vec!["prog", "-v", "file1"])
+    ///             .unwrap_err(),
+    ///         ParseError::UnexpectedPositional { value: "file1".to_string() }
+    ///     );
+    ///
+    ///     // unknown arguments and missing values are still allowed
+    ///     assert!(clappers()
+    ///         .strict_positional()
+    ///         .try_build_from(vec!["prog", "--colour", "-v", "--output"])
+    ///         .is_ok());
+    ///
+    ///     assert!(clappers()
+    ///         .try_build_from(vec!["prog", "-v", "file1"])
+    ///         .is_ok());
+    /// }
+    /// ```
+    ///
+    pub fn strict_positional(self) -> Self {
+        self.no_leftovers()
+    }
+
+    /// Treat negative numbers on the command line as leftovers instead of unknown arguments
+    ///
+    /// Negative numbers that are also configured aliases are still
+    /// parsed as arguments.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let args = vec!["calc", "-v", "-5", "-3.14", "-5abc", "2"];
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .treat_numbers_as_leftovers()
+    ///         .build_from(args.clone());
+    ///
+    ///     assert!(clappers.get_flag("verbose"));
+    ///     assert_eq!(clappers.get_leftovers(), vec!["-5", "-3.14", "2"]);
+    ///     assert_eq!(clappers.get_unknown(), vec!["-5abc"]);
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .build_from(args);
+    ///
+    ///     assert_eq!(clappers.get_leftovers(), vec!["2"]);
+    ///     assert_eq!(clappers.get_unknown(), vec!["-5", "-3.14", "-5abc"]);
+    /// }
+    /// ```
+    ///
+    pub fn treat_numbers_as_leftovers(mut self) -> Self {
+        self.config.numbers_as_leftovers = true;
+        self
+    }
+
+    /// Preallocate room for the parsed command line arguments values
+    ///
+    /// Parsing a command line with thousands of values otherwise grows
+    /// the parsed values one reallocation at a time e.g:
+    ///
+    ///```ignore
+    /// cc -I include1 include2 ... includeN
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `flags` is the number of flag arguments expected on the
+    /// command line.
+    ///
+    /// `singles` is the number of single value arguments expected on
+    /// the command line.
+    ///
+    /// `multiples` is the number of values expected for each multiple
+    /// value argument on the command line.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let mut args = vec!["cc".to_string(), "main.c".to_string(), "-v".to_string()];
+    ///
+    ///     for i in 0..5000 {
+    ///         args.push("-I".to_string());
+    ///         args.push(format!("include{i}"));
+    ///     }
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .set_multiples(vec!["I"])
+    ///         .with_capacity(1, 0, 5000)
+    ///         .build_from(args);
+    ///
+    ///     let includes = clappers.get_multiple("I");
+    ///
+    ///     assert!(clappers.get_flag("verbose"));
+    ///     assert_eq!(includes.len(), 5000);
+    ///     assert_eq!(includes[0], "include0");
+    ///     assert_eq!(includes[4999], "include4999");
+    ///     assert_eq!(clappers.get_leftovers(), vec!["main.c"]);
+    /// }
+    /// ```
+    ///
+    pub fn with_capacity(mut self, flags: usize, singles: usize, multiples: usize) -> Self {
+        self.config.capacity = (flags, singles, multiples);
+        self
+    }
+
+    /// Build the command line arguments parser with the current `Clappers` config
+    ///
+    /// Stdin is only read for arguments configured with `from_stdin()`,
+    /// when they are supplied without any values. Otherwise, stdin is
+    /// never touched.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser containing the parsed command line
     /// arguments values, accessed with:
     ///
-    /// - `get_flags()`
-    /// - `get_singles()`
-    /// - `get_multiples()`
-    /// - `get_leftovers()`
+    /// - `get_flags()`
+    /// - `get_singles()`
+    /// - `get_multiples()`
+    /// - `get_leftovers()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["h|help", "v|verbose"])
+    ///         .set_singles(vec!["o|output", "u|username"])
+    ///         .set_multiples(vec!["i|input", "host"])
+    ///         .build();
+    ///
+    ///     if clappers.get_flag("help") {
+    ///         // Show help text
+    ///     }
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    pub fn build(self) -> Self {
+        self.build_from(env::args())
+    }
+
+    /// Build the command line arguments parser, preallocating room for the parsed command line arguments values
+    ///
+    /// This is a shortcut for `with_capacity()` followed by `build()`.
+    ///
+    /// # Parameters
+    ///
+    /// `flags`, `singles` and `multiples` are the same as for
+    /// `with_capacity()`.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser containing the parsed command line
+    /// arguments values, just like `build()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .set_multiples(vec!["I", "L"])
+    ///         .build_with_capacity(1, 0, 1000);
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    pub fn build_with_capacity(self, flags: usize, singles: usize, multiples: usize) -> Self {
+        self.with_capacity(flags, singles, multiples).build()
+    }
+
+    /// Build the command line arguments parser from the supplied arguments instead of `env::args()`
+    ///
+    /// Just like `build()`, stdin is only read for arguments configured
+    /// with `from_stdin()`, when they are supplied without any values.
+    /// Use `build_from_reader()` to read from something else.
+    ///
+    /// # Parameters
+    ///
+    /// `args` are the command line arguments to parse, starting with
+    /// the program name i.e `argv[0]`
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser containing the parsed command line
+    /// arguments values, just like `build()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["h|help", "v|verbose"])
+    ///         .set_singles(vec!["o|output", "u|username"])
+    ///         .build_from(vec!["prog", "-v", "--output", "a.out", "file1"]);
+    ///
+    ///     assert!(clappers.get_flag("verbose"));
+    ///     assert_eq!(clappers.get_single("o"), "a.out");
+    ///     assert_eq!(clappers.get_leftovers(), vec!["file1"]);
+    /// }
+    /// ```
+    ///
+    pub fn build_from<I>(self, args: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        // stdin is left alone unless something could read from it
+        if self.reads_stdin() {
+            self.build_from_reader(args, io::stdin())
+        } else {
+            self.build_from_reader(args, io::empty())
+        }
+    }
+
+    // whether any argument, including those of subcommands, is configured with from_stdin()
+    fn reads_stdin(&self) -> bool {
+        !self.config.from_stdin.is_empty()
+            || self
+                .config
+                .subcommands
+                .values()
+                .any(|subcommand| subcommand.reads_stdin())
+    }
+
+    /// Build the command line arguments parser from the supplied arguments, reading from the supplied reader instead of stdin
+    ///
+    /// The reader is only read for arguments configured with
+    /// `from_stdin()`.
+    ///
+    /// # Parameters
+    ///
+    /// `args` are the command line arguments to parse, starting with
+    /// the program name i.e `argv[0]`
+    ///
+    /// `reader` is read in place of stdin.
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser containing the parsed command line
+    /// arguments values, just like `build()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["v|verbose"])
+    ///             .set_multiples(vec!["f|files"])
+    ///             .from_stdin("files")
+    ///     };
+    ///
+    ///     let piped = clappers()
+    ///         .build_from_reader(vec!["prog", "--files", "-v"], "file1\nfile2\nfile3\n".as_bytes());
+    ///
+    ///     assert!(piped.get_flag("verbose"));
+    ///     assert_eq!(piped.get_multiple("files"), vec!["file1", "file2", "file3"]);
+    ///
+    ///     let inline = clappers()
+    ///         .build_from_reader(vec!["prog", "--files", "file4"], "file1\n".as_bytes());
+    ///
+    ///     assert_eq!(inline.get_multiple("files"), vec!["file4"]);
+    /// }
+    /// ```
+    ///
+    pub fn build_from_reader<I, R>(mut self, args: I, mut reader: R) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        R: Read,
+    {
+        // how many arguments have been taken from argv, for the position of each leftover
+        let fetched = Cell::new(0);
+        let mut args = args
+            .into_iter()
+            .map(|arg| -> String {
+                fetched.set(fetched.get() + 1);
+                arg.into()
+            })
+            .peekable();
+
+        self.values.flags.reserve(self.config.capacity.0);
+        self.values.singles.reserve(self.config.capacity.1);
+        self.values
+            .multiples
+            .reserve(self.config.multiples.name.len());
+
+        // keep the program name from argv[0] for help text
+        if let Some(argv0) = args.next() {
+            self.values.program = Path::new(&argv0)
+                .file_name()
+                .map_or(argv0.clone(), |f| f.to_string_lossy().to_string());
+        }
+
+        // e.g cargo runs `cargo-foo foo ...` for `cargo foo ...`
+        if let Some(token) = &self.config.skip_leading {
+            args.next_if_eq(token);
+        }
+
+        let mut unknown: Option<String> = None;
+
+        while let Some(mut next) = args.next() {
+            let position = fetched.get() - 1;
+
+            if self.is_terminator(&next) {
+                self.terminate(args.by_ref(), &fetched);
+                break;
+            }
+
+            // a lone dash is conventionally stdin, so it's a leftover rather than an argument
+            if next.starts_with('-') && next != "-" {
+                let token = next.clone();
+                unknown = None;
+
+                next = next.split_off(1);
+
+                if next.starts_with('-') {
+                    next = next.split_off(1);
+                }
+
+                // --=value is missing the argument name, so it's only ever unknown
+                if next.starts_with('=') && !self.values.invalid.contains(&token) {
+                    self.values.invalid.push(token.clone());
+                }
+
+                let mut inline: Option<String> = None;
+
+                // -a=value or --argument=value
+                let equals = next
+                    .split_once('=')
+                    .map(|(argument, value)| (argument.to_string(), value.to_string()));
+
+                if let Some((argument, value)) = equals {
+                    if let Some(name) = self.config.singles.aliases.get(&argument) {
+                        *self.values.occurrences.entry(argument).or_default() += 1;
+                        self.values
+                            .first_positions
+                            .entry(name.to_string())
+                            .or_insert(position);
+                        self.values.dangling.remove(name);
+                        self.values.singles.insert(name.to_string(), value);
+
+                        continue;
+                    }
+
+                    // --input=a b c takes the first value inline, then carries on as usual
+                    if self.config.multiples.aliases.contains_key(&argument) {
+                        next = argument;
+                        inline = Some(value);
+                    }
+                }
+
+                // count each spelling of an argument, for count_occurrences_of()
+                if [
+                    &self.config.flags,
+                    &self.config.singles,
+                    &self.config.multiples,
+                ]
+                .iter()
+                .any(|config_type| config_type.aliases.contains_key(&next))
+                {
+                    *self.values.occurrences.entry(next.clone()).or_default() += 1;
+                    self.values
+                        .first_positions
+                        .entry(self.canonical(&next))
+                        .or_insert(position);
+                }
+
+                if let Some(name) = self.config.flags.aliases.get(&next) {
+                    let name = name.to_string();
+                    let terminates = self.is_one_of(&self.config.terminator_flags, &name);
+
+                    if self.is_one_of(&self.config.short_circuits, &name) {
+                        self.values.short_circuited = Some(name.clone());
+                    }
+
+                    self.values.negated.remove(&name);
+                    self.values.flags.insert(name);
+
+                    if self.values.short_circuited.is_some() {
+                        break;
+                    }
+
+                    if terminates {
+                        self.terminate(args.by_ref(), &fetched);
+                        break;
+                    }
+                } else if let Some(name) = self.config.singles.aliases.get(&next) {
+                    self.read_single(name.to_string(), &mut args);
+                } else if let Some(name) = self.config.multiples.aliases.get(&next) {
+                    if self.values.multiples.get_mut(name).is_none() {
+                        self.values
+                            .multiples
+                            .insert(name.clone(), Vec::with_capacity(self.config.capacity.2));
+                    }
+
+                    let policy = self.merge_policy(name);
+
+                    if policy == MergePolicy::Replace {
+                        self.values.multiples.get_mut(name).unwrap().clear();
+                    }
+
+                    let brackets = self.config.bracketed.get(name).filter(|_| inline.is_none());
+
+                    // --filter [ name -type f ]
+                    if let Some((open, close)) =
+                        brackets.filter(|(open, _)| args.next_if_eq(open).is_some())
+                    {
+                        let values = self.values.multiples.get_mut(name).unwrap();
+                        let mut depth = 1;
+
+                        loop {
+                            match args.next() {
+                                Some(value) => {
+                                    if value == *close {
+                                        depth -= 1;
+
+                                        if depth == 0 {
+                                            break;
+                                        }
+                                    } else if value == *open {
+                                        depth += 1;
+                                    }
+
+                                    if policy != MergePolicy::Unique || !values.contains(&value) {
+                                        values.push(value);
+                                    }
+                                }
+                                None => {
+                                    if !self.values.unbalanced.contains(name) {
+                                        self.values.unbalanced.push(name.to_string());
+                                    }
+
+                                    break;
+                                }
+                            }
+                        }
+
+                        continue;
+                    }
+
+                    let mut supplied = false;
+
+                    if let Some(value) = inline.take() {
+                        let values = self.values.multiples.get_mut(name).unwrap();
+
+                        if policy != MergePolicy::Unique || !values.contains(&value) {
+                            values.push(value);
+                        }
+
+                        supplied = true;
+                    }
+
+                    while let Some(value) = args.peek() {
+                        // terminators end multiple values just like any other argument
+                        if self.is_terminator(value) || value.starts_with('-') {
+                            break;
+                        } else {
+                            let value = args.next().unwrap();
+                            let values = self.values.multiples.get_mut(name).unwrap();
+
+                            if policy != MergePolicy::Unique || !values.contains(&value) {
+                                values.push(value);
+                            }
+
+                            supplied = true;
+                        }
+                    }
+
+                    // --files with no values reads them from stdin, one per line
+                    if !supplied && self.is_one_of(&self.config.from_stdin, name) {
+                        let values = self.values.multiples.get_mut(name).unwrap();
+
+                        for value in BufReader::new(&mut reader).lines().map_while(Result::ok) {
+                            if policy != MergePolicy::Unique || !values.contains(&value) {
+                                values.push(value);
+                            }
+                        }
+                    }
+                } else if let Some(name) = next
+                    .strip_prefix("no-")
+                    .and_then(|flag| self.config.flags.aliases.get(flag).cloned())
+                    .filter(|name| self.is_one_of(&self.config.negatable, name))
+                {
+                    // --no-argument
+                    self.values.flags.remove(&name);
+                    self.values.negated.insert(name);
+                } else if !token.starts_with("--") && self.is_flag_bundle(&next) {
+                    // -zcv, or -zcf where the single takes the rest of the bundle or the next value
+                    let mut terminates = false;
+                    let mut chars = next.chars();
+
+                    while let Some(c) = chars.next() {
+                        let alias = c.to_string();
+
+                        *self.values.occurrences.entry(alias.clone()).or_default() += 1;
+                        self.values
+                            .first_positions
+                            .entry(self.canonical(&alias))
+                            .or_insert(position);
+
+                        if let Some(name) = self.config.flags.aliases.get(&alias).cloned() {
+                            terminates |= self.is_one_of(&self.config.terminator_flags, &name);
+
+                            if self.values.short_circuited.is_none()
+                                && self.is_one_of(&self.config.short_circuits, &name)
+                            {
+                                self.values.short_circuited = Some(name.clone());
+                            }
+
+                            self.values.negated.remove(&name);
+                            self.values.flags.insert(name);
+
+                            continue;
+                        }
+
+                        let name = self.config.singles.aliases[&alias].to_string();
+                        let value = chars.as_str();
+
+                        if value.is_empty() {
+                            self.read_single(name, &mut args);
+                        } else {
+                            self.values.dangling.remove(&name);
+                            self.values.singles.insert(name, value.to_string());
+                        }
+
+                        break;
+                    }
+
+                    if self.values.short_circuited.is_some() {
+                        break;
+                    }
+
+                    if terminates {
+                        self.terminate(args.by_ref(), &fetched);
+                        break;
+                    }
+                } else if self.config.numbers_as_leftovers && is_negative_number(&token) {
+                    // -5 or -3.14
+                    self.values.leftovers.push(token);
+                    self.values.leftover_positions.push(position);
+                } else {
+                    if !self.values.unknown.contains(&token) {
+                        self.values.unknown.push(token.clone());
+                    }
+
+                    unknown = Some(token);
+                }
+            } else if let Some(subcommand) = self
+                .config
+                .subcommands
+                .get(&next)
+                .filter(|_| self.values.leftovers.is_empty())
+            {
+                // the subcommand parses everything after it, with itself as argv[0]
+                let remaining: Vec<String> =
+                    std::iter::once(next.clone()).chain(args.by_ref()).collect();
+                let subcommand = subcommand
+                    .clone()
+                    .build_from_reader(remaining, &mut reader as &mut dyn Read);
+
+                self.values.subcommand = Some((next, Box::new(subcommand)));
+            } else {
+                // values after an unknown argument are still leftovers, but remembered against it
+                if let Some(token) = &unknown {
+                    self.values
+                        .unknown_values
+                        .entry(token.to_string())
+                        .or_default()
+                        .push(next.clone());
+                }
+
+                self.values.leftovers.push(next);
+                self.values.leftover_positions.push(position);
+            }
+        }
+
+        // flags not supplied on the command line fall back to their environment variable
+        for (argument, var) in &self.config.flag_envs {
+            if let Some(name) = self.config.flags.aliases.get(argument) {
+                if !self.values.flags.contains(name)
+                    && !self.values.negated.contains(name)
+                    && env::var(var).is_ok_and(|v| is_truthy(&v))
+                {
+                    self.values.flags.insert(name.to_string());
+                    self.values.env_flags.insert(name.to_string());
+                }
+            }
+        }
+
+        // as do single and multiple value arguments
+        for (argument, var) in &self.config.value_envs {
+            let value = match env::var(var) {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+
+            if let Some(name) = self.config.singles.aliases.get(argument) {
+                if !self.values.singles.contains_key(name) && !self.values.dangling.contains(name) {
+                    self.values.singles.insert(name.to_string(), value);
+                    self.values.env_values.insert(name.to_string());
+                }
+            } else if let Some(name) = self.config.multiples.aliases.get(argument) {
+                if !self.values.multiples.contains_key(name) {
+                    self.values.multiples.insert(name.to_string(), vec![value]);
+                    self.values.env_values.insert(name.to_string());
+                }
+            }
+        }
+
+        self
+    }
+
+    // whether any of the configured aliases belong to the argument with the canonical name
+    fn is_one_of(&self, aliases: &HashSet<String>, name: &str) -> bool {
+        aliases.iter().any(|alias| self.canonical(alias) == name)
+    }
+
+    #[cfg(feature = "json")]
+    fn is_required(&self, name: &str) -> bool {
+        self.config
+            .required
+            .iter()
+            .any(|argument| self.canonical(argument) == name)
+    }
+
+    #[cfg(feature = "json")]
+    fn possible(&self, name: &str) -> Vec<String> {
+        self.config
+            .possible_values
+            .iter()
+            .find(|(argument, _)| self.canonical(argument) == name)
+            .map_or(vec![], |(_, possible)| possible.to_vec())
+    }
+
+    // the description followed by the default value and environment variable, if any
+    fn usage_description(&self, config_type: &ConfigType, name: &str) -> String {
+        let default = config_type
+            .defaults
+            .get(name)
+            .map(|default| format!("[default: {default}]"));
+
+        let env = self
+            .config
+            .flag_envs
+            .iter()
+            .chain(self.config.value_envs.iter().map(|(a, v)| (a, v)))
+            .find(|(argument, _)| {
+                config_type
+                    .aliases
+                    .get(*argument)
+                    .is_some_and(|n| n == name)
+            })
+            .map(|(_, var)| format!("[env: {var}]"));
+
+        let description = Some(self.description(name)).filter(|d| !d.is_empty());
+
+        vec![description, default, env]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn description(&self, name: &str) -> String {
+        self.config
+            .descriptions
+            .iter()
+            .find(|(argument, _)| self.canonical(argument) == name)
+            .map_or(String::new(), |(_, description)| description.to_string())
+    }
+
+    // whether every character is a flag alias, up until a single alias taking the rest as its
+    // value, only checked once exact aliases fail to match
+    fn is_flag_bundle(&self, name: &str) -> bool {
+        name.chars().count() > 1
+            && name
+                .chars()
+                .find(|c| !self.config.flags.aliases.contains_key(&c.to_string()))
+                .map_or(true, |c| {
+                    self.config.singles.aliases.contains_key(&c.to_string())
+                })
+    }
+
+    // terminators and other arguments are never taken as the value
+    fn read_single<I>(&mut self, name: String, args: &mut Peekable<I>)
+    where
+        I: Iterator<Item = String>,
+    {
+        let consumes_dash = self.is_one_of(&self.config.consumes_unknown_dash, &name);
+        let consumes_terminator = self.is_one_of(&self.config.consumes_terminator, &name);
+
+        match args.next_if(|v| {
+            if self.is_terminator(v) {
+                consumes_terminator
+            } else {
+                !v.starts_with('-') || consumes_dash && !self.is_argument(v)
+            }
+        }) {
+            Some(value) => {
+                self.values.dangling.remove(&name);
+                self.values.singles.insert(name, value);
+            }
+            None => {
+                self.values.dangling.insert(name);
+            }
+        }
+    }
+
+    // the configured alias closest to an unknown argument, as long as it's a near miss
+    fn suggest(&self, token: &str) -> Option<String> {
+        let name = token.trim_start_matches('-');
+        let name = name.split_once('=').map_or(name, |(name, _)| name);
+
+        [
+            &self.config.flags,
+            &self.config.singles,
+            &self.config.multiples,
+        ]
+        .iter()
+        .flat_map(|config_type| config_type.specs.iter().flatten())
+        .map(|alias| (edit_distance(name, alias), alias))
+        .filter(|(distance, alias)| distance * 3 <= name.chars().count().max(alias.chars().count()))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, alias)| format_aliases(std::slice::from_ref(alias)))
+    }
+
+    // whether the token would be parsed as a configured argument
+    fn is_argument(&self, token: &str) -> bool {
+        let name = token.strip_prefix('-').unwrap_or(token);
+        let name = name.strip_prefix('-').unwrap_or(name);
+
+        if name.is_empty() {
+            return false;
+        }
+
+        let equals = name.split_once('=').is_some_and(|(name, _)| {
+            self.config.singles.aliases.contains_key(name)
+                || self.config.multiples.aliases.contains_key(name)
+        });
+
+        let negated = name
+            .strip_prefix("no-")
+            .and_then(|flag| self.config.flags.aliases.get(flag))
+            .is_some_and(|flag| self.is_one_of(&self.config.negatable, flag));
+
+        let bundle = !token.starts_with("--") && self.is_flag_bundle(name);
+
+        equals
+            || negated
+            || bundle
+            || self.config.flags.aliases.contains_key(name)
+            || self.config.singles.aliases.contains_key(name)
+            || self.config.multiples.aliases.contains_key(name)
+    }
+
+    fn is_terminator(&self, token: &str) -> bool {
+        self.config.terminators.iter().any(|marker| marker == token)
+    }
+
+    // everything after a terminator is a trailing leftover, even if it looks like an argument
+    fn terminate<I>(&mut self, rest: I, fetched: &Cell<usize>)
+    where
+        I: Iterator<Item = String>,
+    {
+        self.values
+            .trailing
+            .get_or_insert(self.values.leftovers.len());
+
+        for value in rest {
+            self.values.leftovers.push(value);
+            self.values.leftover_positions.push(fetched.get() - 1);
+        }
+    }
+
+    /// Build the command line arguments parser, even if the command line isn't valid unicode
+    ///
+    /// Unlike `build()`, which panics on arguments that aren't valid
+    /// unicode, invalid parts of arguments are replaced with
+    /// `U+FFFD REPLACEMENT CHARACTER`. Use `lossy_args()` to find
+    /// which arguments were replaced.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser containing the parsed command line
+    /// arguments values, just like `build()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output"])
+    ///         .build_lossy();
+    ///
+    ///     if !clappers.lossy_args().is_empty() {
+    ///         eprintln!("warning: some arguments are not valid unicode");
+    ///     }
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    pub fn build_lossy(self) -> Self {
+        self.build_lossy_from(env::args_os())
+    }
+
+    /// Build the command line arguments parser from the supplied arguments, even if they aren't valid unicode
+    ///
+    /// # Parameters
+    ///
+    /// `args` are the command line arguments to parse, starting with
+    /// the program name i.e `argv[0]`
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser containing the parsed command line
+    /// arguments values, just like `build_lossy()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     #[cfg(unix)]
+    ///     {
+    ///         use std::ffi::OsStr;
+    ///         use std::os::unix::ffi::OsStrExt;
+    ///
+    ///         let clappers = Clappers::new()
+    ///             .set_singles(vec!["o|output"])
+    ///             .build_lossy_from(vec![
+    ///                 OsStr::new("prog"),
+    ///                 OsStr::new("-o"),
+    ///                 OsStr::from_bytes(b"caf\xe9.txt"),
+    ///                 OsStr::new("file1"),
+    ///             ]);
+    ///
+    ///         assert_eq!(clappers.get_single("output"), "caf\u{FFFD}.txt");
+    ///         assert_eq!(clappers.get_leftovers(), vec!["file1"]);
+    ///         assert_eq!(clappers.lossy_args(), vec![2]);
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub fn build_lossy_from<I>(self, args: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<OsStr>,
+    {
+        let raw_args: Vec<OsString> = args
+            .into_iter()
+            .map(|arg| arg.as_ref().to_os_string())
+            .collect();
+
+        let mut lossy = Vec::new();
+        let args: Vec<String> = raw_args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| {
+                if arg.to_str().is_none() {
+                    lossy.push(i);
+                }
+
+                arg.to_string_lossy().into_owned()
+            })
+            .collect();
+
+        let mut clappers = self.build_from(args);
+
+        clappers.values.lossy = lossy;
+        clappers.values.raw_args = raw_args;
+        clappers
+    }
+
+    /// Build the command line arguments parser, checking the command line against the `Clappers` config
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser just like `build()` if the command line
+    /// is valid, and the first `ParseError` found otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = match Clappers::new()
+    ///         .set_singles(vec!["u|username"])
+    ///         .max_length("username", 32)
+    ///         .try_build()
+    ///     {
+    ///         Ok(clappers) => clappers,
+    ///         Err(error) => {
+    ///             eprintln!("{error}");
+    ///             std::process::exit(1);
+    ///         }
+    ///     };
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    pub fn try_build(self) -> Result<Self, ParseError> {
+        self.try_build_from(env::args())
+    }
+
+    /// Build the command line arguments parser from the supplied arguments, checking them against the `Clappers` config
+    ///
+    /// # Parameters
+    ///
+    /// `args` are the command line arguments to parse, starting with
+    /// the program name i.e `argv[0]`
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser just like `build_from()` if the command
+    /// line is valid, and the first `ParseError` found otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let result = Clappers::new()
+    ///         .set_singles(vec!["u|username"])
+    ///         .max_length("username", 5)
+    ///         .try_build_from(vec!["prog", "--username", "zelensky"]);
+    ///
+    ///     assert!(result.is_err());
+    /// }
+    /// ```
+    ///
+    /// Malformed arguments missing their name are errors, whereas
+    /// `build_from()` treats them as unknown arguments:
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError};
+    ///
+    /// fn main() {
+    ///     let clappers = || Clappers::new().set_singles(vec!["o|output"]);
+    ///
+    ///     for token in ["--=foo", "-=foo"] {
+    ///         assert_eq!(
+    ///             clappers().try_build_from(vec!["prog", token]).unwrap_err(),
+    ///             ParseError::InvalidToken { token: token.to_string() }
+    ///         );
+    ///
+    ///         let lenient = clappers().build_from(vec!["prog", token, "file1"]);
+    ///
+    ///         assert_eq!(lenient.get_unknown(), vec![token]);
+    ///         assert_eq!(lenient.get_single("output"), "");
+    ///         assert_eq!(lenient.get_leftovers(), vec!["file1"]);
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub fn try_build_from<I>(self, args: I) -> Result<Self, ParseError>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let clappers = self.build_from(args);
+
+        // short circuiting flags like --help ignore the rest of the command line
+        if clappers.values.short_circuited.is_none() {
+            clappers.validate()?;
+        }
+
+        Ok(clappers)
+    }
+
+    fn validate(&self) -> Result<(), ParseError> {
+        if let Some(token) = self.values.invalid.first() {
+            return Err(ParseError::InvalidToken {
+                token: token.to_string(),
+            });
+        }
+
+        if let Some(argument) = self.values.unbalanced.first() {
+            return Err(ParseError::UnbalancedBracket {
+                argument: argument.to_string(),
+            });
+        }
+
+        if let Some(argument) = self
+            .values
+            .unknown
+            .first()
+            .filter(|_| self.config.strict_unknown)
+        {
+            return Err(ParseError::UnknownArgument {
+                argument: argument.to_string(),
+                suggestion: self.suggest(argument),
+            });
+        }
+
+        if let Some(spec) = self
+            .config
+            .singles
+            .specs
+            .iter()
+            .filter(|_| self.config.strict_missing_value)
+            .find(|spec| self.values.dangling.contains(&spec[0]))
+        {
+            return Err(ParseError::MissingValue {
+                argument: spec[0].to_string(),
+            });
+        }
+
+        if let Some(value) = self
+            .values
+            .leftovers
+            .first()
+            .filter(|_| self.config.no_leftovers)
+        {
+            return Err(ParseError::UnexpectedPositional {
+                value: value.to_string(),
+            });
+        }
+
+        for argument in &self.config.required {
+            if !self.has_value(argument) {
+                return Err(ParseError::MissingRequired {
+                    argument: self.canonical(argument),
+                });
+            }
+        }
+
+        for (argument, possible) in &self.config.possible_values {
+            self.check_values(argument, |value| possible.iter().any(|p| p == value))?;
+        }
+
+        for (argument, min) in &self.config.min_lengths {
+            self.check_values(argument, |value| value.chars().count() >= *min)?;
+        }
+
+        for (argument, max) in &self.config.max_lengths {
+            self.check_values(argument, |value| value.chars().count() <= *max)?;
+        }
+
+        for argument in &self.config.reject_empty {
+            if let Some((name, values)) = self.supplied_values(argument) {
+                if values.iter().any(|value| value.is_empty()) {
+                    return Err(ParseError::EmptyValue {
+                        argument: name.to_string(),
+                    });
+                }
+            }
+        }
+
+        for (argument, expected) in &self.config.exact_values {
+            if let Some(name) = self.config.multiples.aliases.get(argument) {
+                if let Some(values) = self.values.multiples.get(name) {
+                    if values.len() != *expected {
+                        return Err(ParseError::WrongValueCount {
+                            argument: name.to_string(),
+                            expected: *expected,
+                            found: values.len(),
+                        });
+                    }
+                }
+            }
+        }
+
+        for (argument, needs) in &self.config.requires_all {
+            if !self.is_supplied(argument) {
+                continue;
+            }
+
+            let missing: Vec<String> = needs
+                .iter()
+                .filter(|need| !self.is_supplied(need))
+                .map(|need| self.canonical(need))
+                .collect();
+
+            if !missing.is_empty() {
+                return Err(ParseError::RequiresAll {
+                    argument: self.canonical(argument),
+                    missing,
+                });
+            }
+        }
+
+        for group in &self.config.together {
+            let missing: Vec<String> = group
+                .iter()
+                .filter(|argument| !self.is_supplied(argument))
+                .map(|argument| self.canonical(argument))
+                .collect();
+
+            if !missing.is_empty() && missing.len() < group.len() {
+                return Err(ParseError::UnmetRequirement {
+                    group: group
+                        .iter()
+                        .map(|argument| self.canonical(argument))
+                        .collect(),
+                    missing,
+                });
+            }
+        }
+
+        for (argument, needs) in &self.config.requires_any {
+            if self.is_supplied(argument) && !needs.iter().any(|need| self.is_supplied(need)) {
+                return Err(ParseError::RequiresAny {
+                    argument: self.canonical(argument),
+                    needs: needs.iter().map(|need| self.canonical(need)).collect(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_values<F>(&self, argument: &str, is_valid: F) -> Result<(), ParseError>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let (name, values) = match self.supplied_values(argument) {
+            Some(supplied) => supplied,
+            None => return Ok(()),
+        };
+
+        match values.into_iter().find(|value| !is_valid(value)) {
+            Some(value) => Err(ParseError::InvalidValue {
+                argument: name.to_string(),
+                value: value.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    // the canonical name of any alias, or the alias itself if it is not configured
+    fn canonical(&self, argument: &str) -> String {
+        [
+            &self.config.flags,
+            &self.config.singles,
+            &self.config.multiples,
+        ]
+        .iter()
+        .find_map(|config_type| config_type.aliases.get(argument))
+        .map_or(argument.to_string(), |name| name.to_string())
+    }
+
+    // how repeated occurrences of the multiple value argument with the canonical name are combined
+    fn merge_policy(&self, name: &str) -> MergePolicy {
+        self.config
+            .multiples_merge
+            .iter()
+            .rev()
+            .find(|(argument, _)| self.canonical(argument) == name)
+            .map_or(MergePolicy::Append, |(_, policy)| *policy)
+    }
+
+    // remember the argument was read, for unread_arguments()
+    fn mark_read(&self, argument: &str) {
+        let name = self.canonical(argument);
+
+        self.values.read.lock().insert(name);
+    }
+
+    // whether the argument was supplied on the command line, whatever its type
+    fn is_supplied(&self, argument: &str) -> bool {
+        self.config
+            .flags
+            .aliases
+            .get(argument)
+            .is_some_and(|name| self.values.flags.contains(name))
+            || self
+                .config
+                .singles
+                .aliases
+                .get(argument)
+                .is_some_and(|name| self.values.singles.contains_key(name))
+            || self
+                .config
+                .multiples
+                .aliases
+                .get(argument)
+                .is_some_and(|name| self.values.multiples.contains_key(name))
+    }
+
+    // whether the argument has a value from anywhere, where a negated flag has none
+    fn has_value(&self, argument: &str) -> bool {
+        !matches!(
+            self.values_source(argument),
+            ValueSource::Unset | ValueSource::Negated
+        )
+    }
+
+    // the canonical name and supplied values of a single or multiple value argument
+    fn supplied_values(&self, argument: &str) -> Option<(&String, Vec<&String>)> {
+        if let Some(name) = self.config.singles.aliases.get(argument) {
+            Some((name, self.values.singles.get(name).into_iter().collect()))
+        } else if let Some(name) = self.config.multiples.aliases.get(argument) {
+            Some((
+                name,
+                self.values
+                    .multiples
+                    .get(name)
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Check if the flag was supplied on the command line for the specified argument
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// `true` if the flag was supplied on the command line, and
+    /// `false` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["h|help"])
+    ///         .build();
+    ///
+    ///     if clappers.get_flag("help") {
+    ///         // Show help text
+    ///     }
+    ///
+    ///     if clappers.get_flag("h") {
+    ///         // This will also show the help text
+    ///     }
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// Single character flags can be combined, but an exact alias
+    /// always wins over a combination:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["n", "a", "m", "e", "v"])
+    ///         .set_singles(vec!["name"])
+    ///         .build_from(vec!["prog", "-nv", "-name", "x"]);
+    ///
+    ///     assert!(clappers.get_flag("n"));
+    ///     assert!(clappers.get_flag("v"));
+    ///     assert!(!clappers.get_flag("a"));
+    ///     assert!(!clappers.get_flag("m"));
+    ///     assert!(!clappers.get_flag("e"));
+    ///     assert_eq!(clappers.get_single("name"), "x");
+    /// }
+    /// ```
+    ///
+    pub fn get_flag(&self, argument: &str) -> bool {
+        self.mark_read(argument);
+        self.config
+            .flags
+            .aliases
+            .get(argument)
+            .is_some_and(|f| self.values.flags.contains(f))
+    }
+
+    /// Check if the flag is set for the specified argument, and where that came from
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// The same `bool` as `get_flag()`, along with its `ValueSource`:
+    ///
+    /// - `ValueSource::CommandLine` if the flag was supplied on the
+    ///   command line
+    /// - `ValueSource::Environment` if set by `flag_env()`
+    /// - `ValueSource::Negated` if its negation was supplied on the
+    ///   command line
+    /// - `ValueSource::Unset` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ValueSource};
+    /// use std::env;
+    ///
+    /// fn main() {
+    ///     env::set_var("FLAG_WITH_SOURCE_QUIET", "true");
+    ///     env::set_var("FLAG_WITH_SOURCE_COLOR", "true");
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose", "q|quiet", "c|color", "d|debug"])
+    ///         .flag_env("quiet", "FLAG_WITH_SOURCE_QUIET")
+    ///         .flag_env("color", "FLAG_WITH_SOURCE_COLOR")
+    ///         .negatable(vec!["color"])
+    ///         .build_from(vec!["prog", "-v", "--no-color"]);
+    ///
+    ///     assert_eq!(clappers.get_flag_with_source("verbose"), (true, ValueSource::CommandLine));
+    ///     assert_eq!(clappers.get_flag_with_source("quiet"), (true, ValueSource::Environment));
+    ///     assert_eq!(clappers.get_flag_with_source("color"), (false, ValueSource::Negated));
+    ///     assert_eq!(clappers.get_flag_with_source("debug"), (false, ValueSource::Unset));
+    /// }
+    /// ```
+    ///
+    pub fn get_flag_with_source(&self, argument: &str) -> (bool, ValueSource) {
+        (self.get_flag(argument), self.values_source(argument))
+    }
+
+    /// Get the single value supplied on the command line for the specified argument
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// The single `String` value if they were supplied on the command
+    /// line, and empty `String` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["output"])
+    ///         .build();
+    ///
+    ///     println!("Output filename is {}", clappers.get_single("output"));
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// Default values configured in the `arg_spec` are used when the
+    /// argument is not supplied on the command line:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output=a.out", "u|username"])
+    ///         .build_from(vec!["prog"]);
+    ///
+    ///     assert_eq!(clappers.get_single("output"), "a.out");
+    ///     assert_eq!(clappers.get_single("o"), "a.out");
+    ///     assert_eq!(clappers.get_single("username"), "");
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output=a.out"])
+    ///         .build_from(vec!["prog", "-o", "b.out"]);
+    ///
+    ///     assert_eq!(clappers.get_single("output"), "b.out");
+    /// }
+    /// ```
+    ///
+    pub fn get_single(&self, argument: &str) -> String {
+        self.mark_read(argument);
+        self.config
+            .singles
+            .aliases
+            .get(argument)
+            .map_or("".to_string(), |s| {
+                self.values
+                    .singles
+                    .get(s)
+                    .or_else(|| self.config.singles.defaults.get(s))
+                    .unwrap_or(&"".to_string())
+                    .to_string()
+            })
+    }
+
+    /// Get the single value supplied on the command line for the specified argument, if any
+    ///
+    /// Unlike `get_single()`, this tells apart a single value argument
+    /// that was not supplied from one that was supplied with an empty
+    /// value e.g `--output=`
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// The single `String` value if it was supplied on the command
+    /// line or by `value_env()`, and `None` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output", "u|username"])
+    ///         .build_from(vec!["prog", "--output="]);
+    ///
+    ///     assert_eq!(clappers.get_single_opt("output"), Some("".to_string()));
+    ///     assert_eq!(clappers.get_single_opt("username"), None);
+    /// }
+    /// ```
+    ///
+    /// Short aliases take equals values too:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output", "u|username"])
+    ///         .build_from(vec!["prog", "-o=file", "-u="]);
+    ///
+    ///     assert_eq!(clappers.get_single_opt("output"), Some("file".to_string()));
+    ///     assert_eq!(clappers.get_single_opt("username"), Some("".to_string()));
+    /// }
+    /// ```
+    ///
+    pub fn get_single_opt(&self, argument: &str) -> Option<String> {
+        self.mark_read(argument);
+        self.config
+            .singles
+            .aliases
+            .get(argument)
+            .and_then(|s| self.values.singles.get(s).cloned())
+    }
+
+    /// Iterate over every single value supplied on the command line
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// An iterator over the canonical name and value of each single
+    /// value argument supplied on the command line, in the order they
+    /// were configured
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .set_singles(vec!["o|output", "u|username", "c|colour=auto"])
+    ///         .set_multiples(vec!["i|input"])
+    ///         .build_from(vec!["prog", "--username", "zelensky", "-v", "-i", "a.c", "-o", "a.out"]);
+    ///
+    ///     let singles: Vec<(&str, &str)> = clappers.singles_iter().collect();
+    ///
+    ///     assert_eq!(singles, vec![("o", "a.out"), ("u", "zelensky")]);
+    /// }
+    /// ```
+    ///
+    pub fn singles_iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.config.singles.specs.iter().filter_map(move |spec| {
+            self.values.singles.get(&spec[0]).map(|value| {
+                self.mark_read(&spec[0]);
+                (spec[0].as_str(), value.as_str())
+            })
+        })
+    }
+
+    /// Get the single value supplied on the command line for the specified argument, or compute one
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// `f` computes the value to use instead, and is only called when
+    /// `get_single()` would return an empty `String`
+    ///
+    /// # Return value
+    ///
+    /// The value just like `get_single()` if it's not empty, and the
+    /// value returned by `f` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    /// use std::cell::Cell;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output", "u|username"])
+    ///         .build_from(vec!["prog", "-o", "a.out"]);
+    ///
+    ///     let calls = Cell::new(0);
+    ///     let whoami = || {
+    ///         calls.set(calls.get() + 1);
+    ///         "zelensky".to_string()
+    ///     };
+    ///
+    ///     assert_eq!(clappers.get_single_or_else("output", whoami), "a.out");
+    ///     assert_eq!(calls.get(), 0);
+    ///
+    ///     assert_eq!(clappers.get_single_or_else("username", whoami), "zelensky");
+    ///     assert_eq!(calls.get(), 1);
+    /// }
+    /// ```
+    ///
+    pub fn get_single_or_else<F>(&self, argument: &str, f: F) -> String
+    where
+        F: FnOnce() -> String,
+    {
+        let value = self.get_single(argument);
+
+        if value.is_empty() {
+            f()
+        } else {
+            value
+        }
+    }
+
+    /// Get the first single value supplied on the command line out of several arguments
+    ///
+    /// This helps replace an old argument with a new one without
+    /// making them aliases, so the old one can be deprecated e.g:
+    ///
+    ///```ignore
+    /// --old-name value
+    /// --new-name value
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `arguments` are aliases of single value arguments, in order of
+    /// precedence
+    ///
+    /// # Return value
+    ///
+    /// The first value that `get_single()` doesn't return as an empty
+    /// `String`, and empty `String` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = || Clappers::new().set_singles(vec!["new-name", "old-name"]);
+    ///
+    ///     let old = clappers().build_from(vec!["prog", "--old-name", "old"]);
+    ///
+    ///     assert_eq!(old.get_single_first_of(&["new-name", "old-name"]), "old");
+    ///
+    ///     let both = clappers().build_from(vec!["prog", "--old-name", "old", "--new-name", "new"]);
+    ///
+    ///     assert_eq!(both.get_single_first_of(&["new-name", "old-name"]), "new");
+    ///
+    ///     let neither = clappers().build_from(vec!["prog"]);
+    ///
+    ///     assert_eq!(neither.get_single_first_of(&["new-name", "old-name"]), "");
+    /// }
+    /// ```
+    ///
+    pub fn get_single_first_of(&self, arguments: &[&str]) -> String {
+        arguments
+            .iter()
+            .map(|argument| self.get_single(argument))
+            .find(|value| !value.is_empty())
+            .unwrap_or_default()
+    }
+
+    /// Get the single value supplied on the command line for the specified argument, split into a list
+    ///
+    /// This suits `PATH` style values e.g:
+    ///
+    ///```ignore
+    /// --path /bin:/usr/bin:/usr/local/bin
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// `sep` is the separator between list items e.g ":" or ";"
+    ///
+    /// # Return value
+    ///
+    /// The non-empty items of the single value if it was supplied on
+    /// the command line, and empty `Vec<String>` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["p|path", "w|winpath", "l|libpath"])
+    ///         .build_from(vec!["prog", "-p", "/a:/b::/c", "-w", "C:\\a;D:\\b"]);
+    ///
+    ///     assert_eq!(clappers.get_single_as_list("path", ":"), vec!["/a", "/b", "/c"]);
+    ///     assert_eq!(clappers.get_single_as_list("winpath", ";"), vec!["C:\\a", "D:\\b"]);
+    ///     assert!(clappers.get_single_as_list("libpath", ":").is_empty());
+    /// }
+    /// ```
+    ///
+    pub fn get_single_as_list(&self, argument: &str, sep: &str) -> Vec<String> {
+        self.get_single(argument)
+            .split(sep)
+            .filter(|item| !item.is_empty())
+            .map(|item| item.to_string())
+            .collect()
+    }
+
+    /// Check if a single value argument was supplied on the command line without its value
+    ///
+    /// A single value argument is dangling when it is the last value
+    /// on the command line, or is followed by another argument or a
+    /// double-dash. The double-dash still ends argument parsing e.g:
+    ///
+    ///```ignore
+    /// -o -- file
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// `true` if the last occurrence of the argument on the command
+    /// line had no value, and `false` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output", "u|username"])
+    ///         .build_from(vec!["prog", "-u", "alfie", "-o", "--", "file"]);
+    ///
+    ///     assert!(clappers.is_dangling("output"));
+    ///     assert!(!clappers.is_dangling("username"));
+    ///     assert_eq!(clappers.get_single_opt("output"), None);
+    ///     assert_eq!(clappers.get_leftovers(), vec!["file"]);
+    ///     assert_eq!(clappers.get_trailing(), vec!["file"]);
+    /// }
+    /// ```
+    ///
+    pub fn is_dangling(&self, argument: &str) -> bool {
+        self.mark_read(argument);
+        self.config
+            .singles
+            .aliases
+            .get(argument)
+            .is_some_and(|s| self.values.dangling.contains(s))
+    }
+
+    /// Get multiple values supplied on the command line for the specified argument
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// Multiple `String` values if they were supplied on the command
+    /// line, and empty `Vec<String>` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["input"])
+    ///         .build();
+    ///
+    ///     println!("Input filenames are {:#?}", clappers.get_multiple("input"));
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// Multiple values stop at a double-dash, with everything after it
+    /// becoming trailing leftovers:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["i|input"])
+    ///         .build_from(vec!["prog", "-i", "a", "b", "--", "c"]);
+    ///
+    ///     assert_eq!(clappers.get_multiple("input"), vec!["a", "b"]);
+    ///     assert_eq!(clappers.get_trailing(), vec!["c"]);
+    ///     assert_eq!(clappers.get_leftovers(), vec!["c"]);
+    /// }
+    /// ```
+    ///
+    pub fn get_multiple(&self, argument: &str) -> Vec<String> {
+        self.mark_read(argument);
+        self.config
+            .multiples
+            .aliases
+            .get(argument)
+            .map_or(vec![], |m| match self.values.multiples.get(m) {
+                Some(values) => values.to_vec(),
+                None => self
+                    .config
+                    .multiples
+                    .defaults
+                    .get(m)
+                    .map_or(vec![], |default| vec![default.to_string()]),
+            })
+    }
+
+    /// Get the first of multiple values supplied on the command line for the specified argument
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// The first `String` value if any were supplied on the command
+    /// line, the default value if one was configured, and `None`
+    /// otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["c|config", "i|input", "host=localhost"])
+    ///         .build_from(vec!["prog", "-c", "a.toml", "-c", "b.toml"]);
+    ///
+    ///     assert_eq!(clappers.get_multiple_first("host"), Some("localhost".to_string()));
+    ///     assert_eq!(clappers.get_multiple_first("config"), Some("a.toml".to_string()));
+    ///     assert_eq!(clappers.get_multiple_first("input"), None);
+    /// }
+    /// ```
+    ///
+    pub fn get_multiple_first(&self, argument: &str) -> Option<String> {
+        self.mark_read(argument);
+        self.config.multiples.aliases.get(argument).and_then(|m| {
+            match self.values.multiples.get(m) {
+                Some(values) => values.first().cloned(),
+                None => self.config.multiples.defaults.get(m).cloned(),
+            }
+        })
+    }
+
+    /// Get the last of multiple values supplied on the command line for the specified argument
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// The last `String` value if any were supplied on the command
+    /// line, the default value if one was configured, and `None`
+    /// otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["c|config", "i|input", "host=localhost"])
+    ///         .build_from(vec!["prog", "-c", "a.toml", "-c", "b.toml"]);
+    ///
+    ///     assert_eq!(clappers.get_multiple_last("host"), Some("localhost".to_string()));
+    ///     assert_eq!(clappers.get_multiple_last("config"), Some("b.toml".to_string()));
+    ///     assert_eq!(clappers.get_multiple_last("input"), None);
+    /// }
+    /// ```
+    ///
+    pub fn get_multiple_last(&self, argument: &str) -> Option<String> {
+        self.mark_read(argument);
+        self.config.multiples.aliases.get(argument).and_then(|m| {
+            match self.values.multiples.get(m) {
+                Some(values) => values.last().cloned(),
+                None => self.config.multiples.defaults.get(m).cloned(),
+            }
+        })
+    }
+
+    /// Get the key-value pairs supplied on the command line for the specified argument
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// Each value split into a key and value at the first "=", where
+    /// later keys override earlier ones. A value without "=" is a key
+    /// with an empty value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["c"])
+    ///         .set_keyvals(vec!["D|define"])
+    ///         .build_from(vec![
+    ///             "cc", "-D", "DEBUG=1", "VERSION=2.0", "-D", "DEBUG=0", "--define", "FLAGS=-O2=x", "NDEBUG", "-c", "main.c",
+    ///         ]);
+    ///
+    ///     let defines = clappers.get_keyval("D");
+    ///
+    ///     assert_eq!(defines.len(), 4);
+    ///     assert_eq!(defines["DEBUG"], "0");
+    ///     assert_eq!(defines["VERSION"], "2.0");
+    ///     assert_eq!(defines["FLAGS"], "-O2=x");
+    ///     assert_eq!(defines["NDEBUG"], "");
+    ///     assert_eq!(clappers.get_leftovers(), vec!["main.c"]);
+    /// }
+    /// ```
+    ///
+    pub fn get_keyval(&self, argument: &str) -> HashMap<String, String> {
+        self.get_multiple(argument)
+            .iter()
+            .map(|keyval| {
+                let (key, value) = keyval.split_once('=').unwrap_or((keyval, ""));
+
+                (key.to_string(), value.to_string())
+            })
+            .collect()
+    }
+
+    /// Get all values supplied on the command line that are not associated with any argument
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// All `String` values supplied on the command line that are not
+    /// associated with any argument, and empty `Vec<String>`
+    /// otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .build();
+    ///
+    ///     println!("`ls *` returned the following filenames: {:#?}", clappers.get_leftovers());
+    ///
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// Everything after a double-dash is a leftover, even values
+    /// that look like arguments:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .build_from(vec!["prog", "--", "-v", "file1", "--output", "file2"]);
+    ///
+    ///     assert!(!clappers.get_flag("verbose"));
+    ///     assert_eq!(clappers.get_single("output"), "");
+    ///     assert_eq!(clappers.get_leftovers(), vec!["-v", "file1", "--output", "file2"]);
+    /// }
+    /// ```
+    ///
+    /// Leftovers are kept apart from every configured argument, so even an
+    /// empty spec can't swallow them, and a lone dash is a leftover too:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["", "i|"])
+    ///         .build_from(vec!["prog", "file1", "-", "file2", "-i", "input"]);
+    ///
+    ///     assert_eq!(clappers.count_configured(), (0, 0, 1));
+    ///     assert_eq!(clappers.get_multiple(""), Vec::<String>::new());
+    ///     assert_eq!(clappers.get_multiple("i"), vec!["input"]);
+    ///     assert_eq!(clappers.get_leftovers(), vec!["file1", "-", "file2"]);
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["", "|"])
+    ///         .build_from(vec!["prog", "file1", "--", "file2"]);
+    ///
+    ///     assert_eq!(clappers.count_configured(), (0, 0, 0));
+    ///     assert!(clappers.get_multiple("").is_empty());
+    ///     assert_eq!(clappers.get_leftovers(), vec!["file1", "file2"]);
+    ///     assert_eq!(clappers.get_trailing(), vec!["file2"]);
+    /// }
+    /// ```
+    ///
+    pub fn get_leftovers(&self) -> Vec<String> {
+        self.values.leftovers.clone()
+    }
+
+    /// Copy the `Clappers` config into a fresh parser without any parsed values
+    ///
+    /// This lets one configured parser act as a template for parsing
+    /// many command lines, while the original is left untouched
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// A `Clappers` parser with the same config, ready to be built
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let template = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .build_from(vec!["prog", "-v"]);
+    ///
+    ///     let first = template.clone_config().build_from(vec!["prog", "-o", "a.out"]);
+    ///     let second = template.clone_config().build_from(vec!["prog", "file1"]);
+    ///
+    ///     assert!(template.get_flag("verbose"));
+    ///
+    ///     assert!(!first.get_flag("verbose"));
+    ///     assert_eq!(first.get_single("output"), "a.out");
+    ///     assert!(first.get_leftovers().is_empty());
+    ///
+    ///     assert!(!second.get_flag("verbose"));
+    ///     assert_eq!(second.get_single("output"), "");
+    ///     assert_eq!(second.get_leftovers(), vec!["file1"]);
+    /// }
+    /// ```
+    ///
+    pub fn clone_config(&self) -> Clappers {
+        Self {
+            config: self.config.clone(),
+            values: Values::new(),
+        }
+    }
+
+    /// Turn a built parser back into its `Clappers` config, without any parsed values
+    ///
+    /// This lets the config be extended and built again, e.g when a
+    /// config file supplied on the command line adds more arguments.
+    /// Unlike `clone_config()`, the built parser is consumed.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The `Clappers` parser so that it can be chained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let args = vec!["prog", "--config", "plugins.conf", "--plugin-dir", "/opt"];
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["c|config"])
+    ///         .build_from(args.clone());
+    ///
+    ///     assert_eq!(clappers.get_single("config"), "plugins.conf");
+    ///     assert_eq!(clappers.get_unknown(), vec!["--plugin-dir"]);
+    ///
+    ///     let clappers = clappers
+    ///         .into_builder()
+    ///         .set_singles(vec!["plugin-dir"])
+    ///         .build_from(args);
+    ///
+    ///     assert_eq!(clappers.get_single("config"), "plugins.conf");
+    ///     assert_eq!(clappers.get_single("plugin-dir"), "/opt");
+    ///     assert!(clappers.get_unknown().is_empty());
+    /// }
+    /// ```
+    ///
+    pub fn into_builder(self) -> Clappers {
+        Self {
+            config: self.config,
+            values: Values::new(),
+        }
+    }
+
+    /// Count the arguments in the `Clappers` config
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The number of configured flag, single value and multiple value
+    /// arguments, where all aliases of an argument count as one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["h|help", "v|verbose", "l"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .set_multiples(vec!["i|input", "I"])
+    ///         .build_from(vec!["prog"]);
+    ///
+    ///     assert_eq!(clappers.count_configured(), (3, 1, 2));
+    /// }
+    /// ```
+    ///
+    pub fn count_configured(&self) -> (usize, usize, usize) {
+        (
+            self.config.flags.name.len(),
+            self.config.singles.name.len(),
+            self.config.multiples.name.len(),
+        )
+    }
+
+    /// Summarise the `Clappers` config for testing
+    ///
+    /// Unlike help text, the summary doesn't depend on the order
+    /// arguments were configured in, so it can be compared against a
+    /// known summary to check a parser is set up correctly.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// One line per configured argument, sorted by type then
+    /// canonical name, listing its sorted aliases and any default
+    /// value, followed by one line per subcommand
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose", "h|help"])
+    ///         .set_singles(vec!["output|o=a.out"])
+    ///         .set_multiples(vec!["i|input", "I"])
+    ///         .set_subcommand("install", Clappers::new());
+    ///
+    ///     assert_eq!(
+    ///         clappers.describe_self(),
+    ///         "flag h: h, help
+    /// flag v: v, verbose
+    /// single output: o, output (default \"a.out\")
+    /// multiple I: I
+    /// multiple i: i, input
+    /// subcommand install
+    /// "
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn describe_self(&self) -> String {
+        let mut summary = String::new();
+
+        for (kind, config_type) in [
+            ("flag", &self.config.flags),
+            ("single", &self.config.singles),
+            ("multiple", &self.config.multiples),
+        ] {
+            let mut names: Vec<&String> = config_type.name.iter().collect();
+            names.sort();
+
+            for name in names {
+                let mut aliases: Vec<&str> = config_type
+                    .aliases
+                    .iter()
+                    .filter(|(_, canonical)| *canonical == name)
+                    .map(|(alias, _)| alias.as_str())
+                    .collect();
+                aliases.sort();
+
+                summary.push_str(&format!("{kind} {name}: {}", aliases.join(", ")));
+
+                if let Some(default) = config_type.defaults.get(name) {
+                    summary.push_str(&format!(" (default {default:?})"));
+                }
+
+                summary.push('\n');
+            }
+        }
+
+        let mut subcommands: Vec<&String> = self.config.subcommands.keys().collect();
+        subcommands.sort();
+
+        for subcommand in subcommands {
+            summary.push_str(&format!("subcommand {subcommand}\n"));
+        }
+
+        summary
+    }
+
+    /// Get all leftover values supplied on the command line as raw bytes
+    ///
+    /// Only available on unix. When built with `build_lossy()` or
+    /// `build_lossy_from()`, leftovers keep their exact bytes even if
+    /// they aren't valid unicode, while arguments are still matched
+    /// against their unicode replacement. Otherwise, these are the
+    /// bytes of `get_leftovers()`.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The bytes of each leftover value, in the same order as
+    /// `get_leftovers()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     #[cfg(unix)]
+    ///     {
+    ///         use std::ffi::OsStr;
+    ///         use std::os::unix::ffi::OsStrExt;
+    ///
+    ///         let clappers = Clappers::new()
+    ///             .set_flags(vec!["v|verbose"])
+    ///             .build_lossy_from(vec![
+    ///                 OsStr::new("prog"),
+    ///                 OsStr::from_bytes(b"caf\xe9.txt"),
+    ///                 OsStr::new("-v"),
+    ///                 OsStr::new("file1"),
+    ///                 OsStr::new("--"),
+    ///                 OsStr::from_bytes(b"\xff\xfe"),
+    ///             ]);
+    ///
+    ///         assert!(clappers.get_flag("verbose"));
+    ///         assert_eq!(clappers.get_leftovers(), vec!["caf\u{FFFD}.txt", "file1", "\u{FFFD}\u{FFFD}"]);
+    ///         assert_eq!(
+    ///             clappers.get_leftovers_bytes(),
+    ///             vec![b"caf\xe9.txt".to_vec(), b"file1".to_vec(), b"\xff\xfe".to_vec()]
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    ///
+    #[cfg(unix)]
+    pub fn get_leftovers_bytes(&self) -> Vec<Vec<u8>> {
+        use std::os::unix::ffi::OsStrExt;
+
+        self.values
+            .leftovers
+            .iter()
+            .zip(&self.values.leftover_positions)
+            .map(
+                |(leftover, position)| match self.values.raw_args.get(*position) {
+                    Some(raw) => raw.as_bytes().to_vec(),
+                    None => leftover.as_bytes().to_vec(),
+                },
+            )
+            .collect()
+    }
+
+    /// Iterate over all values supplied on the command line that are not associated with any argument
+    ///
+    /// Unlike `get_leftovers()`, the values are borrowed rather than
+    /// cloned
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// An iterator over the leftover values, in command line order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["l"])
+    ///         .build_from(vec!["ls", "file1", "-l", "file2", "file3"]);
+    ///
+    ///     let mut leftovers = clappers.leftovers_iter();
+    ///
+    ///     assert_eq!(leftovers.next(), Some("file1"));
+    ///     assert_eq!(leftovers.next(), Some("file2"));
+    ///     assert_eq!(leftovers.next(), Some("file3"));
+    ///     assert_eq!(leftovers.next(), None);
+    /// }
+    /// ```
+    ///
+    pub fn leftovers_iter(&self) -> impl Iterator<Item = &str> {
+        self.values
+            .leftovers
+            .iter()
+            .map(|leftover| leftover.as_str())
+    }
+
+    /// Check if a value was supplied on the command line that is not associated with any argument
+    ///
+    /// # Parameters
+    ///
+    /// `value` is the leftover value to look for
+    ///
+    /// # Return value
+    ///
+    /// `true` if `value` is one of the leftovers, and `false`
+    /// otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["y"])
+    ///         .build_from(vec!["apt-get", "-y", "install", "cargo"]);
+    ///
+    ///     assert!(clappers.leftovers_contains("install"));
+    ///     assert!(!clappers.leftovers_contains("update"));
+    /// }
+    /// ```
+    ///
+    pub fn leftovers_contains(&self, value: &str) -> bool {
+        self.leftovers_iter().any(|leftover| leftover == value)
+    }
+
+    /// Get all leftover values supplied on the command line after a terminator
+    ///
+    /// Terminators are the double-dash argument, and any flags
+    /// configured with `terminator_flag()`
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// All `String` values supplied on the command line after the
+    /// first terminator, and empty `Vec<String>` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["l"])
+    ///         .build_from(vec!["ls", "dir1", "-l", "--", "-file1", "file2"]);
+    ///
+    ///     assert_eq!(clappers.get_trailing(), vec!["-file1", "file2"]);
+    ///     assert_eq!(clappers.get_leftovers(), vec!["dir1", "-file1", "file2"]);
+    /// }
+    /// ```
+    ///
+    /// A multiple value argument stops taking values at the double-dash,
+    /// which can also come first, and only the first double-dash is a
+    /// terminator:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["i|input"])
+    ///         .build_from(vec!["prog", "-i", "a", "b", "--", "c"]);
+    ///
+    ///     assert_eq!(clappers.get_multiple("input"), vec!["a", "b"]);
+    ///     assert_eq!(clappers.get_trailing(), vec!["c"]);
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["i|input"])
+    ///         .build_from(vec!["prog", "-i", "--", "a"]);
+    ///
+    ///     assert!(clappers.get_multiple("input").is_empty());
+    ///     assert_eq!(clappers.get_trailing(), vec!["a"]);
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["i|input"])
+    ///         .build_from(vec!["prog", "--", "-i", "a"]);
+    ///
+    ///     assert!(clappers.get_multiple("input").is_empty());
+    ///     assert_eq!(clappers.get_trailing(), vec!["-i", "a"]);
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_multiples(vec!["i|input"])
+    ///         .build_from(vec!["prog", "-i", "a", "--", "b", "--", "c"]);
+    ///
+    ///     assert_eq!(clappers.get_multiple("input"), vec!["a"]);
+    ///     assert_eq!(clappers.get_trailing(), vec!["b", "--", "c"]);
+    ///     assert_eq!(clappers.get_leftovers(), vec!["b", "--", "c"]);
+    /// }
+    /// ```
+    ///
+    pub fn get_trailing(&self) -> Vec<String> {
+        self.values
+            .trailing
+            .map_or(vec![], |trailing| self.get_leftovers().split_off(trailing))
+    }
+
+    /// Get the arguments to forward to another program
+    ///
+    /// Runner style programs pass everything after a terminator on to
+    /// the program they run e.g:
+    ///
+    ///```ignore
+    /// cargo run -- -v input.txt
+    ///```
+    ///
+    /// This is the same as `get_trailing()`, named for passing straight
+    /// to `std::process::Command::args()`.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// A new `Vec<String>` of every value supplied on the command line
+    /// after the first terminator, and empty `Vec<String>` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    /// use std::process::Command;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["release"])
+    ///         .build_from(vec!["cargo-run", "--release", "--", "-v", "input.txt"]);
+    ///
+    ///     let mut command = Command::new("target/release/prog");
+    ///
+    ///     command.args(clappers.forwarded_args());
+    ///
+    ///     assert!(clappers.get_flag("release"));
+    ///     assert_eq!(command.get_args().collect::<Vec<_>>(), vec!["-v", "input.txt"]);
+    /// }
+    /// ```
+    ///
+    pub fn forwarded_args(&self) -> Vec<String> {
+        self.get_trailing()
+    }
+
+    /// Get the flag argument that stopped argument parsing early, if any
+    ///
+    /// When parsing is stopped by a flag set with `short_circuit_on()`,
+    /// the rest of the command line was never parsed, so the other
+    /// values and leftovers shouldn't be acted on.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The canonical name of the flag argument that stopped argument
+    /// parsing, or `None` if the whole command line was parsed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["h|help", "v|verbose"])
+    ///             .short_circuit_on(vec!["help"])
+    ///     };
+    ///
+    ///     let stopped = clappers().build_from(vec!["prog", "-v", "--help", "file1"]);
+    ///
+    ///     assert_eq!(stopped.terminated_early(), Some("h".to_string()));
+    ///
+    ///     let finished = clappers().build_from(vec!["prog", "-v", "file1"]);
+    ///
+    ///     assert_eq!(finished.terminated_early(), None);
+    /// }
+    /// ```
+    ///
+    pub fn terminated_early(&self) -> Option<String> {
+        self.values.short_circuited.clone()
+    }
+
+    /// Get which command line arguments weren't valid unicode
+    ///
+    /// Only `build_lossy()` and `build_lossy_from()` accept arguments
+    /// that aren't valid unicode.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The positions of the arguments whose invalid parts were
+    /// replaced, where the program name i.e `argv[0]` is position 0,
+    /// and empty `Vec<usize>` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output"])
+    ///         .build_lossy_from(vec!["prog", "-o", "a.out"]);
+    ///
+    ///     assert!(clappers.lossy_args().is_empty());
+    /// }
+    /// ```
+    ///
+    pub fn lossy_args(&self) -> Vec<usize> {
+        self.values.lossy.clone()
+    }
+
+    /// Get where the value of the specified argument came from
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// The `ValueSource` of the value returned by the argument's
+    /// getter, and `ValueSource::Unset` if it has no value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ValueSource};
+    /// use std::env;
+    ///
+    /// fn main() {
+    ///     env::set_var("VALUES_SOURCE_QUIET", "1");
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose", "q|quiet", "d|debug"])
+    ///         .set_singles(vec!["o|output=a.out", "u|username"])
+    ///         .set_multiples(vec!["i|input"])
+    ///         .flag_env("quiet", "VALUES_SOURCE_QUIET")
+    ///         .build_from(vec!["prog", "-v", "-i", "file1"]);
+    ///
+    ///     assert_eq!(clappers.values_source("verbose"), ValueSource::CommandLine);
+    ///     assert_eq!(clappers.values_source("input"), ValueSource::CommandLine);
+    ///     assert_eq!(clappers.values_source("quiet"), ValueSource::Environment);
+    ///     assert_eq!(clappers.values_source("output"), ValueSource::Default);
+    ///     assert_eq!(clappers.values_source("username"), ValueSource::Unset);
+    ///     assert_eq!(clappers.values_source("debug"), ValueSource::Unset);
+    /// }
+    /// ```
+    ///
+    /// A negated flag has no value, so it doesn't count as supplied:
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParseError, ValueSource};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["c|color"])
+    ///             .negatable(vec!["color"])
+    ///             .required(vec!["color"])
+    ///     };
+    ///
+    ///     let parsed = clappers().build_from(vec!["prog", "--no-color"]);
+    ///
+    ///     assert_eq!(parsed.values_source("color"), ValueSource::Negated);
+    ///     assert!(!parsed.get_flag("color"));
+    ///     assert_eq!(parsed.value_count("color"), 0);
+    ///
+    ///     assert_eq!(
+    ///         clappers()
+    ///             .try_build_from(vec!["prog", "--no-color"])
+    ///             .unwrap_err(),
+    ///         ParseError::MissingRequired {
+    ///             argument: "c".to_string(),
+    ///         }
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn values_source(&self, argument: &str) -> ValueSource {
+        if let Some(name) = self.config.flags.aliases.get(argument) {
+            if self.values.env_flags.contains(name) {
+                ValueSource::Environment
+            } else if self.values.flags.contains(name) {
+                ValueSource::CommandLine
+            } else if self.values.negated.contains(name) {
+                ValueSource::Negated
+            } else {
+                ValueSource::Unset
+            }
+        } else if let Some(name) = self.config.singles.aliases.get(argument) {
+            if self.values.env_values.contains(name) {
+                ValueSource::Environment
+            } else if self.values.singles.contains_key(name) {
+                ValueSource::CommandLine
+            } else if self.config.singles.defaults.contains_key(name) {
+                ValueSource::Default
+            } else {
+                ValueSource::Unset
+            }
+        } else if let Some(name) = self.config.multiples.aliases.get(argument) {
+            if self.values.env_values.contains(name) {
+                ValueSource::Environment
+            } else if self.values.multiples.contains_key(name) {
+                ValueSource::CommandLine
+            } else if self.config.multiples.defaults.contains_key(name) {
+                ValueSource::Default
+            } else {
+                ValueSource::Unset
+            }
+        } else {
+            ValueSource::Unset
+        }
+    }
+
+    /// Count how many times an exact alias was supplied on the command line
+    ///
+    /// Unlike the getters, which treat every alias of an argument the
+    /// same, only the supplied spelling is counted e.g for:
+    ///
+    ///```ignore
+    /// -v -v --verbose
+    ///```
+    ///
+    /// `v` was supplied twice, and `verbose` once.
+    ///
+    /// # Parameters
+    ///
+    /// `raw_alias` is the alias to count, without any leading dashes
+    ///
+    /// # Return value
+    ///
+    /// The number of times the alias was supplied on the command line,
+    /// including within combined flags
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose", "q|quiet"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .build_from(vec!["prog", "-v", "-vq", "--verbose", "--output=a.out", "-o", "b.out"]);
+    ///
+    ///     assert_eq!(clappers.count_occurrences_of("v"), 2);
+    ///     assert_eq!(clappers.count_occurrences_of("verbose"), 1);
+    ///     assert_eq!(clappers.count_occurrences_of("q"), 1);
+    ///     assert_eq!(clappers.count_occurrences_of("quiet"), 0);
+    ///     assert_eq!(clappers.count_occurrences_of("output"), 1);
+    ///     assert_eq!(clappers.count_occurrences_of("o"), 1);
+    /// }
+    /// ```
+    ///
+    pub fn count_occurrences_of(&self, raw_alias: &str) -> usize {
+        self.values.occurrences.get(raw_alias).copied().unwrap_or(0)
+    }
+
+    /// Get the leftovers supplied on the command line after an argument
+    ///
+    /// This splits the leftovers around a marker argument e.g for:
+    ///
+    ///```ignore
+    /// prog file1 file2 --then file3 file4
+    ///```
+    ///
+    /// the leftovers after `then` are `file3` and `file4`.
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the argument
+    ///
+    /// # Return value
+    ///
+    /// The leftovers after the first occurrence of the argument on the
+    /// command line, or an empty `Vec` if the argument wasn't supplied
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["t|then", "v|verbose"])
+    ///         .build_from(vec!["prog", "file1", "-v", "file2", "--then", "file3", "--", "-file4"]);
+    ///
+    ///     assert_eq!(clappers.get_leftovers(), vec!["file1", "file2", "file3", "-file4"]);
+    ///     assert_eq!(clappers.remaining_after("t"), vec!["file3", "-file4"]);
+    ///     assert_eq!(clappers.remaining_after("then"), vec!["file3", "-file4"]);
+    ///     assert_eq!(clappers.remaining_after("verbose"), vec!["file2", "file3", "-file4"]);
+    ///     assert!(Clappers::new()
+    ///         .set_flags(vec!["t|then"])
+    ///         .build_from(vec!["prog", "file1"])
+    ///         .remaining_after("then")
+    ///         .is_empty());
+    /// }
+    /// ```
+    ///
+    pub fn remaining_after(&self, argument: &str) -> Vec<String> {
+        let after = match self.values.first_positions.get(&self.canonical(argument)) {
+            Some(after) => *after,
+            None => return vec![],
+        };
+
+        self.values
+            .leftovers
+            .iter()
+            .zip(&self.values.leftover_positions)
+            .filter(|(_, position)| **position > after)
+            .map(|(leftover, _)| leftover.to_string())
+            .collect()
+    }
+
+    /// Get all arguments supplied on the command line whose values were never read
+    ///
+    /// This is a diagnostics aid for finding dead arguments in large
+    /// programs, best used in debug builds only e.g:
+    ///
+    ///```ignore
+    /// if cfg!(debug_assertions) {
+    ///     eprintln!("unread arguments: {:?}", clappers.unread_arguments());
+    /// }
+    ///```
+    ///
+    /// An argument is read by calling any getter for its value with
+    /// any of its aliases, or by `singles_iter()` and
+    /// `get_parsed_values()` returning its value.
+    ///
+    /// Reads are tracked behind a `Mutex`, so a built `Clappers` can
+    /// still be shared between threads.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The canonical names of the unread arguments in the order they
+    /// were configured, flags first, then single value and multiple
+    /// value arguments
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose", "q|quiet"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .build_from(vec!["prog", "--verbose", "-o", "a.out"]);
+    ///
+    ///     assert_eq!(clappers.unread_arguments(), vec!["v", "o"]);
+    ///
+    ///     clappers.get_flag("verbose");
+    ///     clappers.get_flag("quiet");
+    ///
+    ///     assert_eq!(clappers.unread_arguments(), vec!["o"]);
+    /// }
+    /// ```
+    ///
+    /// Every getter counts as a read:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    /// use std::{sync::Arc, thread};
+    ///
+    /// fn main() {
+    ///     let clappers = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["v|verbose", "q|quiet"])
+    ///             .set_singles(vec!["o|output", "u|user"])
+    ///             .set_multiples(vec!["i|input", "I", "D"])
+    ///             .build_from(vec![
+    ///                 "prog", "-v", "-q", "-o", "a.out", "-u", "me", "-i", "a.c", "-I", "inc", "-D", "X=1",
+    ///             ])
+    ///     };
+    ///
+    ///     let parsed = clappers();
+    ///
+    ///     parsed.get_flag_with_source("verbose");
+    ///     parsed.value_count("quiet");
+    ///     parsed.singles_iter().count();
+    ///     parsed.get_multiple_first("input");
+    ///     parsed.get_multiple_last("I");
+    ///     parsed.get_keyval("D");
+    ///
+    ///     assert!(parsed.unread_arguments().is_empty());
+    ///
+    ///     let parsed = clappers();
+    ///
+    ///     parsed.get_single_opt("o");
+    ///     parsed.get_single_or_else("user", String::new);
+    ///     parsed.is_dangling("o");
+    ///
+    ///     assert_eq!(parsed.unread_arguments(), vec!["v", "q", "i", "I", "D"]);
+    ///
+    ///     parsed.get_parsed_values();
+    ///
+    ///     assert!(parsed.unread_arguments().is_empty());
+    ///
+    ///     // reads from other threads are tracked too
+    ///     let shared = Arc::new(clappers());
+    ///     let reader = Arc::clone(&shared);
+    ///
+    ///     thread::spawn(move || reader.get_parsed_values())
+    ///         .join()
+    ///         .unwrap();
+    ///
+    ///     assert!(shared.unread_arguments().is_empty());
+    /// }
+    /// ```
+    ///
+    pub fn unread_arguments(&self) -> Vec<String> {
+        let read = self.values.read.lock();
+
+        [
+            &self.config.flags,
+            &self.config.singles,
+            &self.config.multiples,
+        ]
+        .iter()
+        .flat_map(|config_type| config_type.specs.iter().map(|spec| &spec[0]))
+        .filter(|name| !read.contains(*name))
+        .filter(|name| {
+            matches!(
+                self.values_source(name),
+                ValueSource::CommandLine | ValueSource::Environment
+            )
+        })
+        .map(|name| name.to_string())
+        .collect()
+    }
+
+    /// Get all arguments supplied on the command line that are not in the `Clappers` config
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// Each unknown argument exactly as it was supplied on the
+    /// command line, in the order first seen, and empty `Vec<String>`
+    /// otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .build_from(vec!["prog", "--colour", "-v", "-x", "--colour"]);
+    ///
+    ///     assert_eq!(clappers.get_unknown(), vec!["--colour", "-x"]);
+    /// }
+    /// ```
+    ///
+    pub fn get_unknown(&self) -> Vec<String> {
+        self.values.unknown.to_vec()
+    }
+
+    /// Get the first argument supplied on the command line that is not in the `Clappers` config
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The first unknown argument exactly as it was supplied on the
+    /// command line, and `None` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .build_from(vec!["prog", "-v", "--colour", "-x", "--colour", "file1"]);
+    ///
+    ///     assert_eq!(clappers.first_unknown(), Some("--colour".to_string()));
+    ///     assert_eq!(clappers.get_unknown(), vec!["--colour", "-x"]);
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .build_from(vec!["prog", "-v", "file1"]);
+    ///
+    ///     assert_eq!(clappers.first_unknown(), None);
+    /// }
+    /// ```
+    ///
+    pub fn first_unknown(&self) -> Option<String> {
+        self.values.unknown.first().cloned()
+    }
+
+    /// Get the values supplied on the command line after an argument that is not in the `Clappers` config
+    ///
+    /// These values are leftovers as usual, but are also remembered
+    /// against the unknown argument before them, for passthrough or
+    /// debugging
+    ///
+    /// # Parameters
+    ///
+    /// `token` is the unknown argument exactly as it was supplied on
+    /// the command line
+    ///
+    /// # Return value
+    ///
+    /// The `String` values following every occurrence of `token`,
+    /// and empty `Vec<String>` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .build_from(vec!["prog", "--unknownopt", "a", "b", "-v", "c"]);
+    ///
+    ///     assert_eq!(clappers.get_unknown(), vec!["--unknownopt"]);
+    ///     assert_eq!(clappers.unknown_values("--unknownopt"), vec!["a", "b"]);
+    ///     assert_eq!(clappers.get_leftovers(), vec!["a", "b", "c"]);
+    /// }
+    /// ```
+    ///
+    pub fn unknown_values(&self, token: &str) -> Vec<String> {
+        self.values
+            .unknown_values
+            .get(token)
+            .map_or(vec![], |values| values.to_vec())
+    }
+
+    /// Count the values of the specified argument, whatever its type
+    ///
+    /// # Parameters
+    ///
+    /// `argument` is any alias of the specified argument
+    ///
+    /// # Return value
+    ///
+    /// The number of values the argument's getter returns, i.e:
+    ///
+    /// - flags: 1 if set, and 0 otherwise
+    /// - singles: 1 if supplied or defaulted, and 0 otherwise
+    /// - multiples: the number of values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose", "q|quiet"])
+    ///         .set_singles(vec!["o|output", "u|username"])
+    ///         .set_multiples(vec!["i|input", "I"])
+    ///         .build_from(vec!["prog", "-v", "-o", "a.out", "-i", "file1", "file2"]);
+    ///
+    ///     assert_eq!(clappers.value_count("verbose"), 1);
+    ///     assert_eq!(clappers.value_count("quiet"), 0);
+    ///     assert_eq!(clappers.value_count("output"), 1);
+    ///     assert_eq!(clappers.value_count("username"), 0);
+    ///     assert_eq!(clappers.value_count("input"), 2);
+    ///     assert_eq!(clappers.value_count("I"), 0);
+    /// }
+    /// ```
+    ///
+    pub fn value_count(&self, argument: &str) -> usize {
+        self.mark_read(argument);
+
+        if self.config.multiples.aliases.contains_key(argument) {
+            self.get_multiple(argument).len()
+        } else {
+            usize::from(self.has_value(argument))
+        }
+    }
+
+    /// Get the leftover values supplied before and after a terminator, in one go
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The leftover values supplied before the first terminator, and
+    /// the trailing leftover values supplied after it i.e
+    /// `get_trailing()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .build_from(vec!["run", "script.sh", "-v", "input", "--", "-x", "output"]);
+    ///
+    ///     let (before, after) = clappers.leftovers_split_at_double_dash();
+    ///
+    ///     assert_eq!(before, vec!["script.sh", "input"]);
+    ///     assert_eq!(after, vec!["-x", "output"]);
+    /// }
+    /// ```
+    ///
+    pub fn leftovers_split_at_double_dash(&self) -> (Vec<String>, Vec<String>) {
+        let mut before = self.get_leftovers();
+        let after = before.split_off(self.values.trailing.unwrap_or(before.len()));
+
+        (before, after)
+    }
+
+    /// Get a snapshot of every value parsed from the command line
+    ///
+    /// Snapshots can be compared, which is handy for testing a
+    /// `Clappers` config against an expected parse.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The parsed values, keyed by canonical name
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, ParsedValues};
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose", "q|quiet"])
+    ///         .set_singles(vec!["o|output", "u|username=alfie"])
+    ///         .set_multiples(vec!["i|input"])
+    ///         .build_from(vec!["prog", "--verbose", "--output", "a.out", "-i", "a.c", "b.c", "-", "file1"]);
+    ///
+    ///     let mut expected = ParsedValues::default();
+    ///
+    ///     expected.flags.insert("v".to_string());
+    ///     expected.singles.insert("o".to_string(), "a.out".to_string());
+    ///     expected.multiples.insert("i".to_string(), vec!["a.c".to_string(), "b.c".to_string()]);
+    ///     expected.leftovers = vec!["-".to_string(), "file1".to_string()];
+    ///
+    ///     assert_eq!(clappers.get_parsed_values(), expected);
+    ///     assert_eq!(clappers.get_parsed_values(), clappers.clone().get_parsed_values());
+    /// }
+    /// ```
+    ///
+    pub fn get_parsed_values(&self) -> ParsedValues {
+        for name in self
+            .values
+            .flags
+            .iter()
+            .chain(self.values.singles.keys())
+            .chain(self.values.multiples.keys())
+        {
+            self.mark_read(name);
+        }
+
+        ParsedValues {
+            flags: self
+                .values
+                .flags
+                .iter()
+                .map(|name| self.canonical(name))
+                .collect(),
+            singles: self
+                .values
+                .singles
+                .iter()
+                .map(|(name, value)| (self.canonical(name), value.to_string()))
+                .collect(),
+            multiples: self
+                .values
+                .multiples
+                .iter()
+                .map(|(name, values)| (self.canonical(name), values.to_vec()))
+                .collect(),
+            leftovers: self.values.leftovers.clone(),
+        }
+    }
+
+    /// Get the subcommand supplied on the command line, along with its parser
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The subcommand name and its built `Clappers` parser if a
+    /// subcommand was supplied on the command line, and `None`
+    /// otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_subcommand("update", Clappers::new().set_flags(vec!["f"]))
+    ///         .build_from(vec!["apt-get", "update", "-f"]);
+    ///
+    ///     if let Some((name, update)) = clappers.get_subcommand() {
+    ///         assert_eq!(name, "update");
+    ///         assert!(update.get_flag("f"));
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub fn get_subcommand(&self) -> Option<(&str, &Clappers)> {
+        self.values
+            .subcommand
+            .as_ref()
+            .map(|(name, subcommand)| (name.as_str(), subcommand.as_ref()))
+    }
+
+    /// Route the supplied subcommand to its handler
+    ///
+    /// # Parameters
+    ///
+    /// `handlers` maps subcommand names to the handler to call with
+    /// that subcommand's parser
+    ///
+    /// # Return value
+    ///
+    /// The result of the matching handler if a subcommand with a
+    /// handler was supplied on the command line, and `None` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    /// use std::collections::HashMap;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_subcommand("install", Clappers::new())
+    ///         .set_subcommand("update", Clappers::new().set_flags(vec!["f"]))
+    ///         .build_from(vec!["apt-get", "update", "-f"]);
+    ///
+    ///     let mut handlers: HashMap<&str, Box<dyn Fn(&Clappers) -> String>> = HashMap::new();
+    ///
+    ///     handlers.insert("install", Box::new(|c| format!("install {:?}", c.get_leftovers())));
+    ///     handlers.insert("update", Box::new(|c| format!("update -f={}", c.get_flag("f"))));
+    ///
+    ///     assert_eq!(clappers.dispatch(handlers), Some("update -f=true".to_string()));
+    ///
+    ///     let clappers = Clappers::new()
+    ///         .set_subcommand("install", Clappers::new())
+    ///         .set_subcommand("update", Clappers::new().set_flags(vec!["f"]))
+    ///         .build_from(vec!["apt-get", "install", "cargo"]);
+    ///
+    ///     let mut handlers: HashMap<&str, Box<dyn Fn(&Clappers) -> String>> = HashMap::new();
+    ///
+    ///     handlers.insert("install", Box::new(|c| format!("install {:?}", c.get_leftovers())));
+    ///     handlers.insert("update", Box::new(|c| format!("update -f={}", c.get_flag("f"))));
+    ///
+    ///     assert_eq!(clappers.dispatch(handlers), Some("install [\"cargo\"]".to_string()));
+    /// }
+    /// ```
+    ///
+    pub fn dispatch<T>(&self, handlers: HashMap<&str, Handler<T>>) -> Option<T> {
+        let (name, subcommand) = self.get_subcommand()?;
+
+        handlers.get(name).map(|handler| handler(subcommand))
+    }
+
+    /// Overlay values parsed by another `Clappers` parser onto this one
+    ///
+    /// This lets a tool layer e.g a local command line over a global
+    /// one. Values are merged by canonical name, so both parsers
+    /// should share the same config, or be followed by `normalize()`:
+    ///
+    /// - flags are set if they were set by either parser
+    /// - singles from `other` replace those of this parser
+    /// - multiples from `other` are combined according to `policy`
+    ///
+    /// Leftovers are not merged.
+    ///
+    /// # Parameters
+    ///
+    /// `other` is the built parser whose values take precedence.
+    ///
+    /// `policy` is how multiple values supplied to both parsers are
+    /// combined.
+    ///
+    /// # Return value
+    ///
+    /// None
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, MergePolicy};
+    ///
+    /// fn main() {
+    ///     let config = || {
+    ///         Clappers::new()
+    ///             .set_flags(vec!["v|verbose", "q|quiet"])
+    ///             .set_singles(vec!["o|output", "u|username"])
+    ///             .set_multiples(vec!["I"])
+    ///     };
+    ///
+    ///     let global = config().build_from(vec![
+    ///         "prog", "-v", "-o", "a.out", "-u", "alfie", "-I", "/usr/include",
+    ///     ]);
+    ///     let local = config().build_from(vec!["prog", "-q", "-o", "b.out", "-I", "include"]);
+    ///
+    ///     let mut appended = global.clone();
+    ///     appended.merge_values_from(&local, MergePolicy::Append);
+    ///
+    ///     assert!(appended.get_flag("verbose"));
+    ///     assert!(appended.get_flag("quiet"));
+    ///     assert_eq!(appended.get_single("output"), "b.out");
+    ///     assert_eq!(appended.get_single("username"), "alfie");
+    ///     assert_eq!(appended.get_multiple("I"), vec!["/usr/include", "include"]);
+    ///
+    ///     let mut replaced = global.clone();
+    ///     replaced.merge_values_from(&local, MergePolicy::Replace);
+    ///
+    ///     assert_eq!(replaced.get_multiple("I"), vec!["include"]);
+    /// }
+    /// ```
+    ///
+    pub fn merge_values_from(&mut self, other: &Clappers, policy: MergePolicy) {
+        for name in &other.values.flags {
+            if self.values.flags.insert(name.to_string()) && other.values.env_flags.contains(name) {
+                self.values.env_flags.insert(name.to_string());
+            }
+        }
+
+        for (name, value) in &other.values.singles {
+            self.values
+                .singles
+                .insert(name.to_string(), value.to_string());
+
+            merge_source(&mut self.values.env_values, &other.values.env_values, name);
+        }
+
+        for (name, values) in &other.values.multiples {
+            merge_source(&mut self.values.env_values, &other.values.env_values, name);
+
+            let existing = self.values.multiples.entry(name.to_string()).or_default();
+
+            if policy == MergePolicy::Replace {
+                existing.clear();
+            }
+
+            for value in values {
+                if policy != MergePolicy::Unique || !existing.contains(value) {
+                    existing.push(value.to_string());
+                }
+            }
+        }
+    }
+
+    /// Key every parsed value by its canonical name
+    ///
+    /// Parsing always keys values by canonical name, but values merged
+    /// from a parser with a differently ordered config can end up
+    /// keyed by an alias instead. This moves those values to their
+    /// canonical name, where single values already keyed by the
+    /// canonical name take precedence, and multiple values are
+    /// appended.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// None
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::{Clappers, MergePolicy};
+    ///
+    /// fn main() {
+    ///     let global = Clappers::new()
+    ///         .set_singles(vec!["o|output"])
+    ///         .build_from(vec!["prog", "-o", "a.out"]);
+    ///
+    ///     let mut local = Clappers::new()
+    ///         .set_singles(vec!["output|o"])
+    ///         .build_from(vec!["prog"]);
+    ///
+    ///     local.merge_values_from(&global, MergePolicy::Append);
+    ///
+    ///     assert_eq!(local.get_single("output"), "");
+    ///
+    ///     local.normalize();
+    ///
+    ///     assert_eq!(local.get_single("output"), "a.out");
+    ///     assert_eq!(local.get_single("o"), "a.out");
+    /// }
+    /// ```
+    ///
+    pub fn normalize(&mut self) {
+        let flags = &self.config.flags.aliases;
+
+        for set in [
+            &mut self.values.flags,
+            &mut self.values.env_flags,
+            &mut self.values.negated,
+        ] {
+            *set = set
+                .drain()
+                .map(|name| flags.get(&name).cloned().unwrap_or(name))
+                .collect();
+        }
+
+        let singles = &self.config.singles.aliases;
+        let (canonical, aliased): (HashMap<String, String>, HashMap<String, String>) = self
+            .values
+            .singles
+            .drain()
+            .partition(|(name, _)| singles.get(name).map_or(true, |c| c == name));
+
+        self.values.singles = canonical;
+
+        for (alias, value) in aliased {
+            self.values
+                .singles
+                .entry(singles[&alias].to_string())
+                .or_insert(value);
+        }
+
+        self.values.dangling = self
+            .values
+            .dangling
+            .drain()
+            .map(|name| singles.get(&name).cloned().unwrap_or(name))
+            .collect();
+
+        let multiples = &self.config.multiples.aliases;
+
+        self.values.env_values = self
+            .values
+            .env_values
+            .drain()
+            .map(|name| {
+                singles
+                    .get(&name)
+                    .or_else(|| multiples.get(&name))
+                    .cloned()
+                    .unwrap_or(name)
+            })
+            .collect();
+
+        let multiples = &self.config.multiples.aliases;
+        let (canonical, aliased): (HashMap<String, Vec<String>>, HashMap<String, Vec<String>>) =
+            self.values
+                .multiples
+                .drain()
+                .partition(|(name, _)| multiples.get(name).map_or(true, |c| c == name));
+
+        self.values.multiples = canonical;
+
+        for (alias, values) in aliased {
+            self.values
+                .multiples
+                .entry(multiples[&alias].to_string())
+                .or_default()
+                .extend(values);
+        }
+    }
+
+    /// Write the values of every single and multiple value argument into a map
+    ///
+    /// Useful for combining command line arguments with other sources
+    /// of config. Multiple values are joined by commas, see
+    /// `flatten_into_with()` to choose the separator.
+    ///
+    /// # Parameters
+    ///
+    /// `map` is where the values are written, keyed by the canonical
+    /// name of each argument. Existing entries for the same argument
+    /// are overwritten, and arguments without a value are skipped.
+    ///
+    /// # Return value
+    ///
+    /// None
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    /// use std::collections::HashMap;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output=a.out", "u|username"])
+    ///         .set_multiples(vec!["i|input", "host"])
+    ///         .build_from(vec!["prog", "-i", "file1", "file2"]);
+    ///
+    ///     let mut config = HashMap::new();
+    ///
+    ///     config.insert("editor".to_string(), "vi".to_string());
+    ///     clappers.flatten_into(&mut config);
+    ///
+    ///     assert_eq!(config.len(), 3);
+    ///     assert_eq!(config["editor"], "vi");
+    ///     assert_eq!(config["o"], "a.out");
+    ///     assert_eq!(config["i"], "file1,file2");
+    /// }
+    /// ```
+    ///
+    pub fn flatten_into(&self, map: &mut HashMap<String, String>) {
+        self.flatten_into_with(map, ",");
+    }
+
+    /// Write the values of every single and multiple value argument into a map, joining multiple values with a separator
+    ///
+    /// # Parameters
+    ///
+    /// `map` is where the values are written, keyed by the canonical
+    /// name of each argument. Existing entries for the same argument
+    /// are overwritten, and arguments without a value are skipped.
+    ///
+    /// `separator` is placed between each of the multiple values.
+    ///
+    /// # Return value
+    ///
+    /// None
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    /// use std::collections::HashMap;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_singles(vec!["o|output"])
+    ///         .set_multiples(vec!["I"])
+    ///         .build_from(vec!["prog", "-o", "a.out", "-I", "/usr/include", "include"]);
+    ///
+    ///     let mut config = HashMap::new();
+    ///
+    ///     clappers.flatten_into_with(&mut config, ":");
+    ///
+    ///     assert_eq!(config["o"], "a.out");
+    ///     assert_eq!(config["I"], "/usr/include:include");
+    /// }
+    /// ```
+    ///
+    pub fn flatten_into_with(&self, map: &mut HashMap<String, String>, separator: &str) {
+        for spec in &self.config.singles.specs {
+            if self.has_value(&spec[0]) {
+                map.insert(spec[0].to_string(), self.get_single(&spec[0]));
+            }
+        }
+
+        for spec in &self.config.multiples.specs {
+            if self.has_value(&spec[0]) {
+                map.insert(
+                    spec[0].to_string(),
+                    self.get_multiple(&spec[0]).join(separator),
+                );
+            }
+        }
+    }
+
+    /// Generate help text from the `Clappers` config
+    ///
+    /// Each argument's description is followed by its default value
+    /// and environment variable, if configured.
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// Help text listing every configured argument with its aliases,
+    /// grouped by argument type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["h|help", "v|verbose"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .set_multiples(vec!["i|input", "I"])
+    ///         .build_from(vec!["/usr/bin/compile", "-h"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers.usage(),
+    ///         "usage: compile [arguments]
+    ///
+    /// Flags:
+    ///     -h|--help
+    ///     -v|--verbose
+    ///
+    /// Single value arguments:
+    ///     -o|--output <value>
+    ///
+    /// Multiple value arguments:
+    ///     -i|--input <value1> ... <valueN>
+    ///     -I <value1> ... <valueN>
+    /// "
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// Default values and environment variables are shown after the
+    /// description:
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["v|verbose"])
+    ///         .set_singles(vec!["o|output=a.out"])
+    ///         .describe("output", "Output filename")
+    ///         .flag_env("verbose", "PROG_VERBOSE")
+    ///         .build_from(vec!["prog"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers.usage(),
+    ///         "usage: prog [arguments]
+    ///
+    /// Flags:
+    ///     -v|--verbose          [env: PROG_VERBOSE]
+    ///
+    /// Single value arguments:
+    ///     -o|--output <value>   Output filename [default: a.out]
+    /// "
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn usage(&self) -> String {
+        let mut sections: Vec<(&str, Vec<(String, String)>)> = Vec::new();
+
+        for kind in &self.config.usage_order {
+            let (title, config_type, placeholder) = match kind {
+                ArgKind::Flag => ("Flags", &self.config.flags, ""),
+                ArgKind::Single => ("Single value arguments", &self.config.singles, " <value>"),
+                ArgKind::Multiple => (
+                    "Multiple value arguments",
+                    &self.config.multiples,
+                    " <value1> ... <valueN>",
+                ),
+            };
+
+            if config_type.specs.is_empty() {
+                continue;
+            }
+
+            let lines = config_type
+                .specs
+                .iter()
+                .map(|spec| {
+                    (
+                        format!("{}{placeholder}", format_aliases(spec)),
+                        self.usage_description(config_type, &spec[0]),
+                    )
+                })
+                .collect();
+
+            sections.push((title, lines));
+        }
+
+        let leftovers = self.leftovers_name();
+
+        if self.config.leftovers_metavar.is_some() || self.config.leftovers_description.is_some() {
+            let description = self
+                .config
+                .leftovers_description
+                .clone()
+                .unwrap_or_default();
+
+            sections.push(("Leftovers", vec![(leftovers.to_string(), description)]));
+        }
+
+        // descriptions line up in a column after the widest aliases
+        let column = sections
+            .iter()
+            .flat_map(|(_, lines)| lines.iter().map(|(aliases, _)| aliases.chars().count()))
+            .max()
+            .unwrap_or(0)
+            + 7;
+
+        let mut usage = format!("usage: {} [arguments]", self.values.program);
+
+        if self.config.leftovers_metavar.is_some() {
+            usage.push_str(&format!(" [{leftovers}]"));
+        }
+
+        usage.push('\n');
+
+        for (title, lines) in sections {
+            usage.push_str(&format!("\n{title}:\n"));
+
+            for (aliases, description) in lines {
+                let mut line = format!("    {aliases}");
+
+                for (i, text) in wrap(&description, self.config.usage_width.saturating_sub(column))
+                    .iter()
+                    .enumerate()
+                {
+                    if i > 0 {
+                        usage.push_str(&format!("{line}\n"));
+                        line = String::new();
+                    }
+
+                    line = format!("{line:column$}{text}");
+                }
+
+                usage.push_str(&format!("{line}\n"));
+            }
+        }
+
+        usage
+    }
+
+    /// Generate a terse, man page style synopsis from the `Clappers` config
+    ///
+    /// Flags with a single character alias are bundled together, and
+    /// values are named after the argument's first long alias e.g:
+    ///
+    ///```ignore
+    /// usage: prog [-hv] [-o OUTPUT] [-i INPUT...] [ARGS...]
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// None
+    ///
+    /// # Return value
+    ///
+    /// The synopsis as a single line
     ///
     /// # Example
     ///
@@ -520,91 +6707,170 @@ impl Clappers {
     ///
     /// fn main() {
     ///     let clappers = Clappers::new()
-    ///         .set_flags(vec!["h|help", "v|verbose"])
-    ///         .set_singles(vec!["o|output", "u|username"])
+    ///         .set_flags(vec!["h|help", "v|verbose", "dry-run"])
+    ///         .set_singles(vec!["o|output", "u"])
     ///         .set_multiples(vec!["i|input", "host"])
-    ///         .build();
-    ///
-    ///     if clappers.get_flag("help") {
-    ///         // Show help text
-    ///     }
+    ///         .build_from(vec!["prog"]);
     ///
-    ///     // ...
+    ///     assert_eq!(
+    ///         clappers.synopsis(),
+    ///         "usage: prog [-hv] [--dry-run] [-o OUTPUT] [-u VALUE] [-i INPUT...] [--host HOST...] [ARGS...]"
+    ///     );
     /// }
     /// ```
     ///
-    pub fn build(mut self) -> Self {
-        // setup "leftovers" before parsing
-        self.config.multiples.name.insert("".to_string());
-        self.config
-            .multiples
-            .aliases
-            .insert("".to_string(), "".to_string());
+    pub fn synopsis(&self) -> String {
+        let mut synopsis = format!("usage: {}", self.values.program);
 
-        let mut args = env::args().peekable();
+        for kind in &self.config.usage_order {
+            match kind {
+                ArgKind::Flag => {
+                    let (short, long): (Vec<&Vec<String>>, Vec<&Vec<String>>) = self
+                        .config
+                        .flags
+                        .specs
+                        .iter()
+                        .partition(|spec| short_alias(spec).is_some());
 
-        // discard argv[0]
-        args.next();
+                    if !short.is_empty() {
+                        let bundle: String =
+                            short.iter().filter_map(|spec| short_alias(spec)).collect();
 
-        while let Some(mut next) = args.next() {
-            if next.starts_with('-') {
-                next = next.split_off(1);
+                        synopsis.push_str(&format!(" [-{bundle}]"));
+                    }
 
-                if next.starts_with('-') {
-                    next = next.split_off(1);
+                    for spec in long {
+                        synopsis.push_str(&format!(" [--{}]", spec[0]));
+                    }
                 }
-
-                if let Some(name) = self.config.flags.aliases.get(&next) {
-                    self.values.flags.insert(name.to_string());
-                } else if let Some(name) = self.config.singles.aliases.get(&next) {
-                    if let Some(v) = args.peek() {
-                        if v.starts_with('-') {
-                            continue;
-                        } else {
-                            self.values
-                                .singles
-                                .insert(name.to_string(), args.next().unwrap());
-                        }
+                ArgKind::Single => {
+                    for spec in &self.config.singles.specs {
+                        synopsis.push_str(&format!(
+                            " [{} {}]",
+                            synopsis_alias(spec),
+                            metavar(spec)
+                        ));
                     }
-                } else if let Some(name) = self.config.multiples.aliases.get(&next) {
-                    if self.values.multiples.get_mut(name).is_none() {
-                        self.values.multiples.insert(name.clone(), vec![]);
+                }
+                ArgKind::Multiple => {
+                    for spec in &self.config.multiples.specs {
+                        synopsis.push_str(&format!(
+                            " [{} {}...]",
+                            synopsis_alias(spec),
+                            metavar(spec)
+                        ));
                     }
+                }
+            }
+        }
 
-                    while let Some(value) = args.peek() {
-                        if value.starts_with('-') {
-                            break;
-                        } else {
-                            self.values
-                                .multiples
-                                .get_mut(name)
-                                .unwrap()
-                                .push(args.next().unwrap());
-                        }
+        synopsis.push_str(&format!(" [{}]", self.leftovers_name()));
+        synopsis
+    }
+
+    /// Generate fish shell completions from the `Clappers` config
+    ///
+    /// Each argument gets a `complete` line with its aliases and
+    /// description, with `-r` marking arguments that take a value. To
+    /// install them, save the completions to fish's completions
+    /// directory e.g:
+    ///
+    ///```ignore
+    /// ~/.config/fish/completions/prog.fish
+    ///```
+    ///
+    /// # Parameters
+    ///
+    /// `program` is the name of the command being completed
+    ///
+    /// # Return value
+    ///
+    /// The completions, one line per argument
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use clappers::Clappers;
+    ///
+    /// fn main() {
+    ///     let clappers = Clappers::new()
+    ///         .set_flags(vec!["h|help", "dry-run"])
+    ///         .set_singles(vec!["o|output"])
+    ///         .set_multiples(vec!["i|input|I"])
+    ///         .describe("help", "Print this help")
+    ///         .describe("output", "Where it's written")
+    ///         .build_from(vec!["prog"]);
+    ///
+    ///     assert_eq!(
+    ///         clappers.generate_fish_completion("prog"),
+    ///         "complete -c prog -s h -l help -d 'Print this help'
+    /// complete -c prog -l dry-run
+    /// complete -c prog -s o -l output -r -d 'Where it\\'s written'
+    /// complete -c prog -s i -l input -s I -r
+    /// "
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub fn generate_fish_completion(&self, program: &str) -> String {
+        let mut completion = String::new();
+
+        for kind in &self.config.usage_order {
+            let (config_type, takes_value) = match kind {
+                ArgKind::Flag => (&self.config.flags, false),
+                ArgKind::Single => (&self.config.singles, true),
+                ArgKind::Multiple => (&self.config.multiples, true),
+            };
+
+            for spec in &config_type.specs {
+                let mut line = format!("complete -c {}", fish_quote(program));
+
+                for alias in spec {
+                    match alias.chars().count() {
+                        1 => line.push_str(&format!(" -s {}", fish_quote(alias))),
+                        _ => line.push_str(&format!(" -l {}", fish_quote(alias))),
                     }
                 }
-            } else {
-                if self.values.multiples.get_mut("").is_none() {
-                    self.values.multiples.insert("".to_string(), vec![]);
+
+                if takes_value {
+                    line.push_str(" -r");
+                }
+
+                let description = self.description(&spec[0]);
+
+                if !description.is_empty() {
+                    line.push_str(&format!(" -d {}", fish_quote(&description)));
                 }
 
-                self.values.multiples.get_mut("").unwrap().push(next);
+                completion.push_str(&format!("{line}\n"));
             }
         }
 
-        self
+        completion
     }
 
-    /// Check if the flag was supplied on the command line for the specified argument
+    /// Generate a man page from the `Clappers` config
+    ///
+    /// The man page is written in troff, with NAME, SYNOPSIS (from
+    /// `synopsis()`) and OPTIONS sections, where each argument is listed
+    /// with its aliases and description. The NAME section describes
+    /// the program with the text set by `about()`, falling back to the
+    /// program name if there isn't any. To view it, save it to a file
+    /// and use `man` e.g:
+    ///
+    ///```ignore
+    /// man ./prog.1
+    ///```
     ///
     /// # Parameters
     ///
-    /// `argument` is any alias of the specified argument
+    /// `program` is the name of the command being documented
+    ///
+    /// `section` is the manual section, usually 1 for user commands
     ///
     /// # Return value
     ///
-    /// `true` if the flag was supplied on the command line, and
-    /// `false` otherwise
+    /// The man page
     ///
     /// # Example
     ///
@@ -614,38 +6880,124 @@ impl Clappers {
     /// fn main() {
     ///     let clappers = Clappers::new()
     ///         .set_flags(vec!["h|help"])
-    ///         .build();
+    ///         .set_singles(vec!["o|output=a.out"])
+    ///         .set_multiples(vec!["I"])
+    ///         .describe("help", "Print this help")
+    ///         .describe("I", "Include directories")
+    ///         .about("compile things")
+    ///         .build_from(vec!["prog"]);
     ///
-    ///     if clappers.get_flag("help") {
-    ///         // Show help text
-    ///     }
-    ///
-    ///     if clappers.get_flag("h") {
-    ///         // This will also show the help text
-    ///     }
+    ///     assert_eq!(
+    ///         clappers.generate_manpage("prog", 1),
+    ///         r".TH PROG 1
+    /// .SH NAME
+    /// prog \- compile things
+    /// .SH SYNOPSIS
+    /// .B prog
+    /// [\-h] [\-o OUTPUT] [\-I VALUE...] [ARGS...]
+    /// .SH OPTIONS
+    /// .TP
+    /// \fB\-h\fR, \fB\-\-help\fR
+    /// Print this help
+    /// .TP
+    /// \fB\-o\fR, \fB\-\-output\fR \fIOUTPUT\fR
+    /// [default: a.out]
+    /// .TP
+    /// \fB\-I\fR \fIVALUE\fR...
+    /// Include directories
+    /// "
+    ///     );
     ///
-    ///     // ...
+    ///     assert!(Clappers::new()
+    ///         .generate_manpage("prog", 1)
+    ///         .contains(".SH NAME\nprog \\- prog\n"));
     /// }
     /// ```
     ///
-    pub fn get_flag(&self, argument: &str) -> bool {
+    pub fn generate_manpage(&self, program: &str, section: u8) -> String {
+        let about = self.config.about.as_deref().unwrap_or(program);
+
+        let mut manpage = format!(
+            ".TH {} {section}\n.SH NAME\n{} \\- {}\n.SH SYNOPSIS\n.B {}\n",
+            roff_escape(&program.to_uppercase()),
+            roff_escape(program),
+            roff_escape(about),
+            roff_escape(program),
+        );
+
+        // the synopsis without its "usage: program" prefix
+        let synopsis = self.synopsis();
+        let prefix = format!("usage: {}", self.values.program);
+
+        manpage.push_str(&format!(
+            "{}\n.SH OPTIONS\n",
+            roff_escape(synopsis.strip_prefix(&prefix).unwrap_or(&synopsis).trim())
+        ));
+
+        for kind in &self.config.usage_order {
+            let (config_type, placeholder) = match kind {
+                ArgKind::Flag => (&self.config.flags, ""),
+                ArgKind::Single => (&self.config.singles, ""),
+                ArgKind::Multiple => (&self.config.multiples, "..."),
+            };
+
+            for spec in &config_type.specs {
+                let mut aliases = spec
+                    .iter()
+                    .map(|alias| match alias.chars().count() {
+                        1 => format!(r"\fB\-{}\fR", roff_escape(alias)),
+                        _ => format!(r"\fB\-\-{}\fR", roff_escape(alias)),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                if *kind != ArgKind::Flag {
+                    aliases.push_str(&format!(
+                        r" \fI{}\fR{placeholder}",
+                        roff_escape(&metavar(spec))
+                    ));
+                }
+
+                manpage.push_str(&format!(".TP\n{aliases}\n"));
+
+                let description = self.usage_description(config_type, &spec[0]);
+
+                if !description.is_empty() {
+                    manpage.push_str(&format!("{}\n", roff_escape(&description)));
+                }
+            }
+        }
+
+        manpage
+    }
+
+    fn leftovers_name(&self) -> &str {
         self.config
-            .flags
-            .aliases
-            .get(argument)
-            .map_or(false, |f| self.values.flags.contains(f))
+            .leftovers_metavar
+            .as_deref()
+            .unwrap_or("ARGS...")
     }
 
-    /// Get the single value supplied on the command line for the specified argument
+    /// Generate a machine readable description of the `Clappers` config as JSON
+    ///
+    /// Requires the `json` feature, which serializes with `serde_json`.
+    /// The JSON is an object containing
+    /// the program name, and an array of every configured argument
+    /// with its canonical name, aliases, type (`flag`, `single` or
+    /// `multiple`), description, default value, whether it is
+    /// required, and its possible values e.g:
+    ///
+    ///```ignore
+    /// {"program":"prog","arguments":[{"name":"h","aliases":["h","help"],"type":"flag",...}]}
+    ///```
     ///
     /// # Parameters
     ///
-    /// `argument` is any alias of the specified argument
+    /// None
     ///
     /// # Return value
     ///
-    /// The single `String` value if they were supplied on the command
-    /// line, and empty `String` otherwise
+    /// The JSON as a single line
     ///
     /// # Example
     ///
@@ -654,39 +7006,69 @@ impl Clappers {
     ///
     /// fn main() {
     ///     let clappers = Clappers::new()
-    ///         .set_singles(vec!["output"])
-    ///         .build();
-    ///
-    ///     println!("Output filename is {}", clappers.get_single("output"));
+    ///         .set_flags(vec!["h|help"])
+    ///         .set_singles(vec!["color=auto"])
+    ///         .describe("help", "Print this help")
+    ///         .required(vec!["color"])
+    ///         .possible_values("color", vec!["auto", "never"])
+    ///         .build_from(vec!["prog"]);
     ///
-    ///     // ...
+    ///     assert_eq!(
+    ///         clappers.help_json(),
+    ///         concat!(
+    ///             r#"{"program":"prog","arguments":["#,
+    ///             r#"{"name":"h","aliases":["h","help"],"type":"flag","description":"Print this help","#,
+    ///             r#""default":null,"required":false,"possible_values":[]},"#,
+    ///             r#"{"name":"color","aliases":["color"],"type":"single","description":"","#,
+    ///             r#""default":"auto","required":true,"possible_values":["auto","never"]}"#,
+    ///             r#"]}"#,
+    ///         )
+    ///     );
     /// }
     /// ```
     ///
-    pub fn get_single(&self, argument: &str) -> String {
-        self.config
-            .singles
-            .aliases
-            .get(argument)
-            .map_or("".to_string(), |s| {
-                self.values
-                    .singles
-                    .get(s)
-                    .unwrap_or(&"".to_string())
-                    .to_string()
-            })
+    #[cfg(feature = "json")]
+    pub fn help_json(&self) -> String {
+        let mut arguments: Vec<JsonArgument> = Vec::new();
+
+        for (kind, config_type) in [
+            ("flag", &self.config.flags),
+            ("single", &self.config.singles),
+            ("multiple", &self.config.multiples),
+        ] {
+            for spec in &config_type.specs {
+                let name = &spec[0];
+
+                arguments.push(JsonArgument {
+                    name,
+                    aliases: spec,
+                    kind,
+                    description: self.description(name),
+                    default: config_type.defaults.get(name).map(|d| d.as_str()),
+                    required: self.is_required(name),
+                    possible_values: self.possible(name),
+                });
+            }
+        }
+
+        let help = JsonHelp {
+            program: &self.values.program,
+            arguments,
+        };
+
+        // only strings, bools and arrays of them, which always serialize
+        serde_json::to_string(&help).unwrap_or_default()
     }
 
-    /// Get multiple values supplied on the command line for the specified argument
+    /// Print help text generated from the `Clappers` config to stdout
     ///
     /// # Parameters
     ///
-    /// `argument` is any alias of the specified argument
+    /// None
     ///
     /// # Return value
     ///
-    /// Multiple `String` values if they were supplied on the command
-    /// line, and empty `Vec<String>` otherwise
+    /// None
     ///
     /// # Example
     ///
@@ -695,36 +7077,28 @@ impl Clappers {
     ///
     /// fn main() {
     ///     let clappers = Clappers::new()
-    ///         .set_multiples(vec!["input"])
+    ///         .set_flags(vec!["h|help"])
     ///         .build();
     ///
-    ///     println!("Input filenames are {:#?}", clappers.get_multiple("input"));
-    ///
-    ///     // ...
+    ///     if clappers.get_flag("help") {
+    ///         clappers.print_help();
+    ///     }
     /// }
     /// ```
     ///
-    pub fn get_multiple(&self, argument: &str) -> Vec<String> {
-        self.config
-            .multiples
-            .aliases
-            .get(argument)
-            .map_or(vec![], |m| {
-                self.values.multiples.get(m).unwrap_or(&vec![]).to_vec()
-            })
+    pub fn print_help(&self) {
+        print!("{}", self.usage());
     }
 
-    /// Get all values supplied on the command line that are not associated with any argument
+    /// Write help text generated from the `Clappers` config to the specified writer
     ///
     /// # Parameters
     ///
-    /// None
+    /// `w` is where to write the help text to e.g stderr, or a buffer
     ///
     /// # Return value
     ///
-    /// All `String` values supplied on the command line that are not
-    /// associated with any argument, and empty `Vec<String>`
-    /// otherwise
+    /// Any error from writing to `w`
     ///
     /// # Example
     ///
@@ -733,15 +7107,147 @@ impl Clappers {
     ///
     /// fn main() {
     ///     let clappers = Clappers::new()
-    ///         .build();
+    ///         .set_flags(vec!["h|help"])
+    ///         .build_from(vec!["prog"]);
     ///
-    ///     println!("`ls *` returned the following filenames: {:#?}", clappers.get_leftovers());
+    ///     let mut buffer: Vec<u8> = Vec::new();
     ///
-    ///     // ...
+    ///     clappers.print_help_to(&mut buffer).unwrap();
+    ///
+    ///     assert_eq!(
+    ///         String::from_utf8(buffer).unwrap(),
+    ///         "usage: prog [arguments]\n\nFlags:\n    -h|--help\n"
+    ///     );
     /// }
     /// ```
     ///
-    pub fn get_leftovers(&self) -> Vec<String> {
-        self.get_multiple("")
+    pub fn print_help_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        w.write_all(self.usage().as_bytes())
+    }
+}
+
+// a merged value comes from wherever the merged parser's value came from
+fn merge_source(env_values: &mut HashSet<String>, other: &HashSet<String>, name: &str) {
+    if other.contains(name) {
+        env_values.insert(name.to_string());
+    } else {
+        env_values.remove(name);
+    }
+}
+
+// set or replace the limit for an argument, keeping the order limits were first set in
+fn set_limit(limits: &mut Vec<(String, usize)>, argument: &str, n: usize) {
+    match limits.iter_mut().find(|(limited, _)| limited == argument) {
+        Some(limit) => limit.1 = n,
+        None => limits.push((argument.to_string(), n)),
+    }
+}
+
+// -h|--help
+fn format_aliases(aliases: &[String]) -> String {
+    aliases
+        .iter()
+        .map(|alias| match alias.chars().count() {
+            1 => format!("-{alias}"),
+            _ => format!("--{alias}"),
+        })
+        .collect::<Vec<String>>()
+        .join("|")
+}
+
+// the number of single character insertions, deletions or substitutions between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+// the first single character alias
+fn short_alias(aliases: &[String]) -> Option<&str> {
+    aliases
+        .iter()
+        .find(|alias| alias.chars().count() == 1)
+        .map(|alias| alias.as_str())
+}
+
+// -o, or --output when there is no single character alias
+fn synopsis_alias(aliases: &[String]) -> String {
+    short_alias(aliases).map_or(format!("--{}", aliases[0]), |alias| format!("-{alias}"))
+}
+
+// OUTPUT for o|output
+fn metavar(aliases: &[String]) -> String {
+    aliases
+        .iter()
+        .find(|alias| alias.chars().count() > 1)
+        .map_or("VALUE".to_string(), |alias| alias.to_uppercase())
+}
+
+// single quoted for fish when it contains anything other than plain characters
+fn fish_quote(s: &str) -> String {
+    if !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || "-_./".contains(c)) {
+        return s.to_string();
+    }
+
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+// backslashes and dashes are special in troff, as are leading dots and quotes
+fn roff_escape(s: &str) -> String {
+    let escaped = s.replace('\\', r"\e").replace('-', r"\-");
+
+    if escaped.starts_with('.') || escaped.starts_with('\'') {
+        format!(r"\&{escaped}")
+    } else {
+        escaped
+    }
+}
+
+// greedily fit words into lines of at most `width` characters, unless a word is longer
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for word in text.split_whitespace() {
+        match lines.last_mut() {
+            Some(line) if line.chars().count() + 1 + word.chars().count() <= width => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_string()),
+        }
+    }
+
+    lines
+}
+
+// -5 or -3.14, but not -inf or -5abc
+fn is_negative_number(token: &str) -> bool {
+    token.strip_prefix('-').is_some_and(|number| {
+        number.chars().all(|c| c.is_ascii_digit() || c == '.') && number.parse::<f64>().is_ok()
+    })
+}
+
+fn is_truthy(value: &str) -> bool {
+    ["1", "true", "yes", "on"]
+        .iter()
+        .any(|truthy| value.eq_ignore_ascii_case(truthy))
+}
+
+impl Default for Clappers {
+    fn default() -> Self {
+        Self::new()
     }
 }